@@ -0,0 +1,204 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::helpers;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+
+/// The answer to a part: a number, a string, or a rendered grid such as a CRT screen.
+/// Written to JSON as a number, a string or an array of rows. Its `Display` form is what is
+/// compared, stored and submitted, with one line per row for grids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Grid(Grid),
+}
+
+impl Answer {
+    /// Why a grid answer could not be read as letters. Such a picture is shown to be read by eye,
+    /// but it is not a solved answer that can be stored or submitted.
+    pub fn unread_letters(&self) -> Option<String> {
+        match self {
+            Answer::Grid(grid) => helpers::ocr(grid.rows()).err(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(grid) => write!(f, "{}", grid),
+        }
+    }
+}
+
+// `serde_json::Value` holds no `i128`, so numbers beyond 64 bits are written as strings.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => match (i64::try_from(*n), u64::try_from(*n)) {
+                (Ok(n), _) => serializer.serialize_i64(n),
+                (_, Ok(n)) => serializer.serialize_u64(n),
+                _ => serializer.collect_str(n),
+            },
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Grid(grid) => grid.serialize(serializer),
+        }
+    }
+}
+
+// untagged enums cannot deserialize `i128`, so the variant is picked by hand.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl<'de> Visitor<'de> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a number, a string or an array of rows")
+            }
+
+            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+                Ok(Answer::Number(n as i128))
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+                Ok(Answer::Number(n as i128))
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+                Ok(Answer::Text(s.to_string()))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Answer, A::Error> {
+                let mut rows = vec![];
+                while let Some(row) = seq.next_element()? {
+                    rows.push(row);
+                }
+                Ok(Answer::Grid(Grid::new(rows)))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Grid> for Answer {
+    fn from(grid: Grid) -> Self {
+        Answer::Grid(grid)
+    }
+}
+
+/// Rows of pixels, rendered as `#` for lit and `.` for dark.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Grid {
+    rows: Vec<String>,
+}
+
+impl Grid {
+    pub fn new(rows: Vec<String>) -> Self {
+        Grid { rows }
+    }
+
+    /// Renders rows of lit (`true`) and dark pixels.
+    pub fn from_pixels<R, P>(pixels: R) -> Self
+    where
+        R: IntoIterator<Item = P>,
+        P: IntoIterator<Item = bool>,
+    {
+        let rows = pixels
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|lit| if lit { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Grid { rows }
+    }
+
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(13_u64), Answer::Number(13));
+        assert_eq!(Answer::from(-4_i32), Answer::Number(-4));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::from_pixels([[true, false], [false, true]]);
+        assert_eq!(grid.rows(), ["#.", ".#"]);
+        assert_eq!(Answer::from(grid).to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_json() {
+        let answers: Vec<Answer> = serde_json::from_str(r##"[13, "CMZ", ["#.", ".#"]]"##).unwrap();
+        assert_eq!(
+            answers,
+            [
+                Answer::Number(13),
+                Answer::Text("CMZ".to_string()),
+                Answer::Grid(Grid::new(vec!["#.".to_string(), ".#".to_string()])),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&answers).unwrap(),
+            r##"[13,"CMZ",["#.",".#"]]"##
+        );
+        assert_eq!(
+            serde_json::json!(Answer::from(u64::MAX)),
+            serde_json::json!(u64::MAX)
+        );
+    }
+}
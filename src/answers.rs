@@ -0,0 +1,169 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::store::Store;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+pub fn answers_path(year: i16) -> String {
+    format!("data/{}/answers.json", year)
+}
+
+/// Accepted answers keyed by day (e.g. `05`), input hash and part, as stored in `data/<year>/answers.json`.
+///
+/// Answers are keyed by day rather than by day name, so variants such as `05_sub_optimal`
+/// are verified against the same answers as the main implementation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    pub version: u32,
+    pub days: BTreeMap<String, BTreeMap<String, BTreeMap<u8, String>>>,
+}
+
+impl Default for Answers {
+    fn default() -> Self {
+        Answers {
+            version: <Answers as Store>::VERSION,
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+impl Store for Answers {
+    const VERSION: u32 = 1;
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, input_hash: &str, part: u8) -> Option<&String> {
+        self.days
+            .get(&format!("{:02}", day))
+            .and_then(|inputs| inputs.get(input_hash))
+            .and_then(|parts| parts.get(&part))
+    }
+
+    pub fn insert(&mut self, day: u8, input_hash: &str, part: u8, answer: &str) {
+        self.days
+            .entry(format!("{:02}", day))
+            .or_default()
+            .entry(input_hash.to_string())
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    /// Whether a part has an accepted answer for any input.
+    pub fn is_solved(&self, day: u8, part: u8) -> bool {
+        self.days
+            .get(&format!("{:02}", day))
+            .is_some_and(|inputs| inputs.values().any(|parts| parts.contains_key(&part)))
+    }
+
+    pub fn verify(&self, day: u8, input_hash: &str, part: u8, answer: &str) -> Verdict {
+        match self.get(day, input_hash, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(expected.clone()),
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Records an answer as accepted, keeping a different accepted answer unless `overwrite` is set.
+    /// Returns the verdict against the answers as they were before.
+    pub fn accept(
+        &mut self,
+        day: u8,
+        input_hash: &str,
+        part: u8,
+        answer: &str,
+        overwrite: bool,
+    ) -> Verdict {
+        let verdict = self.verify(day, input_hash, part, answer);
+        match verdict {
+            Verdict::Unknown => self.insert(day, input_hash, part, answer),
+            Verdict::Wrong(_) if overwrite => self.insert(day, input_hash, part, answer),
+            _ => {}
+        }
+        verdict
+    }
+}
+
+/// Whether an answer matches the accepted answer for its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Holds the accepted answer.
+    Wrong(String),
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong(_) => "wrong",
+            Verdict::Unknown => "unknown",
+        };
+        write!(f, "{}", verdict)
+    }
+}
+
+/// A stable hash of the puzzle input (64-bit FNV-1a), so answers stay valid across Rust versions.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1\n2\n"), input_hash("1\n3\n"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut answers = Answers::default();
+        let hash = input_hash("R 4\nU 4\n");
+        answers.insert(9, &hash, 1, "13");
+
+        assert_eq!(answers.verify(9, &hash, 1, "13"), Verdict::Correct);
+        assert_eq!(
+            answers.verify(9, &hash, 1, "14"),
+            Verdict::Wrong("13".to_string())
+        );
+        assert_eq!(answers.verify(9, &hash, 2, "1"), Verdict::Unknown);
+        assert_eq!(
+            answers.verify(9, &input_hash("other"), 1, "13"),
+            Verdict::Unknown
+        );
+        assert!(answers.is_solved(9, 1));
+        assert!(!answers.is_solved(9, 2));
+        assert!(!answers.is_solved(10, 1));
+    }
+
+    #[test]
+    fn test_accept() {
+        let mut answers = Answers::default();
+        assert_eq!(answers.accept(9, "abc", 1, "13", false), Verdict::Unknown);
+        assert_eq!(answers.accept(9, "abc", 1, "13", false), Verdict::Correct);
+        assert_eq!(
+            answers.accept(9, "abc", 1, "14", false),
+            Verdict::Wrong("13".to_string())
+        );
+        assert_eq!(answers.verify(9, "abc", 1, "13"), Verdict::Correct);
+        assert_eq!(
+            answers.accept(9, "abc", 1, "14", true),
+            Verdict::Wrong("13".to_string())
+        );
+        assert_eq!(answers.verify(9, "abc", 1, "14"), Verdict::Correct);
+    }
+}
@@ -0,0 +1,284 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::BenchConfig;
+use crate::input::{Source, INPUT_ENV};
+use crate::output::Format;
+use crate::solution;
+use std::convert::Infallible;
+use std::env;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::time::Duration;
+
+const DEFAULT_THRESHOLD_PERCENT: f64 = 10_f64;
+pub const DEFAULT_TIMEOUT_SECS: u64 = 60;
+
+/// The days picked by `--days`, e.g. `1-10,15`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<RangeInclusive<u8>>);
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.iter().any(|range| range.contains(&day))
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |value: &str| match value.trim().parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(day),
+            _ => Err(format!("invalid day `{}`, expected 1 to 25", value)),
+        };
+        s.split(',')
+            .map(|range| {
+                let (start, end) = match range.split_once('-') {
+                    Some((start, end)) => (day(start)?, day(end)?),
+                    None => (day(range)?, day(range)?),
+                };
+                if start > end {
+                    return Err(format!(
+                        "invalid range `{}`, the first day comes after the last",
+                        range
+                    ));
+                }
+                Ok(start..=end)
+            })
+            .collect::<Result<_, _>>()
+            .map(DaySelection)
+    }
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part `{}`, expected 1 or 2", value)),
+    }
+}
+
+/// Flags shared by the single-day binaries and the all-days runner.
+#[derive(Debug, Clone)]
+pub struct RunArgs {
+    /// Set by `--year/-y`, defaulting to `$AOC_YEAR`.
+    pub year: i16,
+    /// Set by `--input <path>` or `$AOC_INPUT` to read the input from elsewhere, `-` for stdin.
+    pub input: Option<Source>,
+    pub format: Format,
+    /// Set by `--bench`, optionally with a time budget per part via `--bench-time <ms>`.
+    pub bench: Option<BenchConfig>,
+    /// Set by `--compare`: the relative slowdown that counts as a regression, from `--threshold <percent>`.
+    pub compare: Option<f64>,
+    /// Set by `--save-timings`.
+    pub save_timings: bool,
+    /// Set by `--accept`: records the answers of solved parts as known answers.
+    pub accept: bool,
+    /// Set by `--overwrite`: lets `--accept` replace accepted answers that differ.
+    pub overwrite: bool,
+    /// The time a part may take before it is reported as timed out, from `--timeout <seconds>`.
+    /// `--timeout 0` disables it.
+    pub timeout: Option<Duration>,
+    /// Set by `--param name=value`, overrides a puzzle parameter of the day. Can be repeated.
+    pub params: Vec<(String, i64)>,
+    /// Set by `--days 1-10,15`, only runs the given days. All days are run if not set.
+    pub days: Option<DaySelection>,
+    /// Set by `--part 1|2`, only runs the given part.
+    pub part: Option<u8>,
+    /// Set by `--example`: runs against `src/examples` instead of the real input.
+    pub example: bool,
+}
+
+impl RunArgs {
+    pub fn parse(mut args: pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let run_args = RunArgs::parse_flags(&mut args)?;
+
+        let remaining = args.finish();
+        if !remaining.is_empty() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unexpected arguments: {:?}", remaining),
+            });
+        }
+
+        Ok(run_args)
+    }
+
+    /// Takes the run flags out of `args`, leaving free arguments such as the day to the caller.
+    pub fn parse_flags(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let bench = args.contains("--bench");
+        let bench_time: Option<u64> = args.opt_value_from_str("--bench-time")?;
+        let compare = args.contains("--compare");
+        let threshold: f64 = args
+            .opt_value_from_str("--threshold")?
+            .unwrap_or(DEFAULT_THRESHOLD_PERCENT);
+
+        let run_args = RunArgs {
+            year: args
+                .opt_value_from_str(["-y", "--year"])?
+                .unwrap_or_else(crate::default_year),
+            input: args
+                .opt_value_from_fn("--input", |value| Ok::<_, Infallible>(Source::parse(value)))?,
+            format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+            bench: match (bench, bench_time) {
+                (_, Some(ms)) => Some(BenchConfig::with_budget(Duration::from_millis(ms))),
+                (true, None) => Some(BenchConfig::default()),
+                (false, None) => None,
+            },
+            compare: compare.then_some(threshold / 100_f64),
+            save_timings: args.contains("--save-timings"),
+            accept: args.contains("--accept"),
+            overwrite: args.contains("--overwrite"),
+            timeout: match args.opt_value_from_str("--timeout")? {
+                Some(0) => None,
+                Some(secs) => Some(Duration::from_secs(secs)),
+                None => Some(Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
+            },
+            params: args.values_from_fn("--param", solution::parse_param)?,
+            days: args.opt_value_from_str("--days")?,
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            example: args.contains("--example"),
+        };
+
+        if run_args.example && run_args.input.is_some() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--input cannot be combined with --example".to_string(),
+            });
+        }
+
+        if run_args.overwrite && !run_args.accept {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--overwrite only applies with --accept".to_string(),
+            });
+        }
+
+        Ok(run_args)
+    }
+
+    /// Reads the input from `$AOC_INPUT` if neither `--input` nor `--example` is passed.
+    pub fn apply_env(&mut self) {
+        if self.input.is_none() && !self.example {
+            self.input = env::var(INPUT_ENV).ok().map(|value| Source::parse(&value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    fn parse(args: &[&str]) -> Result<RunArgs, pico_args::Error> {
+        RunArgs::parse(pico_args::Arguments::from_vec(
+            args.iter().map(OsString::from).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(parse(&["--year", "2021"]).unwrap().year, 2021);
+        assert_eq!(parse(&["-y", "2020"]).unwrap().year, 2020);
+        assert!(parse(&["--year", "twenty"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse(&[]).unwrap().input, None);
+        assert_eq!(parse(&["--input", "-"]).unwrap().input, Some(Source::Stdin));
+        assert_eq!(
+            parse(&["--input", "big.txt"]).unwrap().input,
+            Some(Source::File("big.txt".into()))
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(parse(&[]).unwrap().format, Format::Text);
+        assert_eq!(parse(&["--format", "csv"]).unwrap().format, Format::Csv);
+        assert!(parse(&["--format", "yaml"]).is_err());
+        assert!(parse(&["--unknown"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(parse(&[]).unwrap().bench, None);
+        assert_eq!(
+            parse(&["--bench"]).unwrap().bench,
+            Some(BenchConfig::default())
+        );
+        assert_eq!(
+            parse(&["--bench-time", "200"])
+                .unwrap()
+                .bench
+                .unwrap()
+                .budget,
+            Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn test_parse_compare() {
+        let args = parse(&[]).unwrap();
+        assert_eq!(args.compare, None);
+        assert!(!args.save_timings);
+
+        assert_eq!(parse(&["--compare"]).unwrap().compare, Some(0.1));
+        assert_eq!(
+            parse(&["--compare", "--threshold", "25"]).unwrap().compare,
+            Some(0.25)
+        );
+        assert!(parse(&["--save-timings"]).unwrap().save_timings);
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(
+            parse(&[]).unwrap().timeout,
+            Some(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+        );
+        assert_eq!(
+            parse(&["--timeout", "5"]).unwrap().timeout,
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(parse(&["--timeout", "0"]).unwrap().timeout, None);
+    }
+
+    #[test]
+    fn test_parse_params() {
+        assert!(parse(&[]).unwrap().params.is_empty());
+        assert_eq!(
+            parse(&["--param", "row=10", "--param", "bound=20"])
+                .unwrap()
+                .params,
+            vec![("row".to_string(), 10), ("bound".to_string(), 20)]
+        );
+        assert!(parse(&["--param", "row"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_selection() {
+        let args = parse(&[]).unwrap();
+        assert_eq!((args.days, args.part, args.example), (None, None, false));
+
+        let days = parse(&["--days", "1-10,15"]).unwrap().days.unwrap();
+        assert!(days.contains(1) && days.contains(10) && days.contains(15));
+        assert!(!days.contains(11) && !days.contains(25));
+        assert!(parse(&["--days", "10-1"]).is_err());
+        assert!(parse(&["--days", "26"]).is_err());
+
+        assert_eq!(parse(&["--part", "2"]).unwrap().part, Some(2));
+        assert!(parse(&["--part", "3"]).is_err());
+
+        assert!(parse(&["--example"]).unwrap().example);
+        assert!(parse(&["--example", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_accept() {
+        let args = parse(&["--accept"]).unwrap();
+        assert!(args.accept && !args.overwrite);
+        assert!(parse(&["--accept", "--overwrite"]).unwrap().overwrite);
+        assert!(parse(&["--overwrite"]).is_err());
+    }
+}
@@ -0,0 +1,159 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Controls how long a part is warmed up and measured in `--bench` mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub warmup: Duration,
+    /// The time budget for measured iterations. The iteration count is derived from it.
+    pub budget: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: Duration::from_millis(100),
+            budget: Duration::from_secs(1),
+            min_samples: 5,
+            max_samples: 10_000,
+        }
+    }
+}
+
+impl BenchConfig {
+    pub fn with_budget(budget: Duration) -> Self {
+        BenchConfig {
+            budget,
+            warmup: budget / 10,
+            ..BenchConfig::default()
+        }
+    }
+
+    /// Picks a sample count that fits the time budget, given the estimated cost of one iteration.
+    fn samples_for(&self, estimate: Duration) -> usize {
+        let fitting = if estimate.is_zero() {
+            self.max_samples
+        } else {
+            (self.budget.as_nanos() / estimate.as_nanos()) as usize
+        };
+        fitting.clamp(self.min_samples, self.max_samples)
+    }
+}
+
+/// Summary statistics over the measured iterations of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "cannot compute stats without samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, {} samples",
+            self.median, self.mean, self.stddev, self.min, self.samples
+        )
+    }
+}
+
+/// Warms up `func`, then runs it as many times as fit the configured budget.
+pub fn bench<T>(config: &BenchConfig, mut func: impl FnMut() -> T) -> Stats {
+    let warmup = Instant::now();
+    let mut warmup_runs = 0_u32;
+    while warmup_runs == 0 || warmup.elapsed() < config.warmup {
+        std::hint::black_box(func());
+        warmup_runs += 1;
+    }
+    let estimate = warmup.elapsed() / warmup_runs;
+
+    let samples: Vec<Duration> = (0..config.samples_for(estimate))
+        .map(|_| {
+            let timer = Instant::now();
+            std::hint::black_box(func());
+            timer.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2]
+            .iter()
+            .map(|&n| Duration::from_micros(n))
+            .collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2_500));
+        assert_eq!(stats.mean, Duration::from_nanos(2_500));
+        // sample standard deviation of 1, 2, 3, 4 µs.
+        assert_eq!(stats.stddev, Duration::from_nanos(1_291));
+    }
+
+    #[test]
+    fn test_bench_respects_sample_bounds() {
+        let config = BenchConfig {
+            warmup: Duration::ZERO,
+            budget: Duration::from_millis(1),
+            min_samples: 3,
+            max_samples: 50,
+        };
+
+        let mut calls = 0;
+        let stats = bench(&config, || calls += 1);
+        assert!((3..=50).contains(&stats.samples));
+        assert_eq!(calls, stats.samples + 1);
+
+        let slow = bench(&config, || std::thread::sleep(Duration::from_millis(2)));
+        assert_eq!(slow.samples, 3);
+    }
+}
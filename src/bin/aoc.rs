@@ -0,0 +1,7 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+fn main() {
+    advent_of_code::cli::main();
+}
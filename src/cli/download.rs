@@ -0,0 +1,44 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{CliError, CommandResult};
+use crate::client::{self, Client};
+use crate::input;
+use pico_args::Arguments;
+use std::fs;
+
+struct Args {
+    day: u8,
+    year: i16,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, CliError> {
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(crate::default_year);
+    let day = args.free_from_str()?;
+    super::finish(args)?;
+    Ok(Args { day, year })
+}
+
+pub fn run(args: Arguments) -> CommandResult {
+    let args = parse_args(args)?;
+
+    let input_path = crate::file_path("inputs", args.year, args.day);
+    let session = client::session()?;
+
+    println!("Downloading input for day {}, {}...", args.day, args.year);
+    let input = Client::new(&session).input(args.year, args.day)?;
+
+    let resolved = input::crate_path(&input_path);
+    if let Some(parent) = resolved.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("could not create \"{}\": {}", parent.display(), e))?;
+    }
+
+    fs::write(&resolved, input).map_err(|e| format!("could not write input file: {}", e))?;
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    Ok(true)
+}
@@ -0,0 +1,273 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use pico_args::Arguments;
+use std::fmt;
+use std::process;
+
+pub mod download;
+pub mod read;
+pub mod readme;
+pub mod scaffold;
+pub mod solve;
+pub mod stars;
+pub mod status;
+pub mod submit;
+
+/// Why a command could not run. Exits with status 2 for usage errors and 1 otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    /// Invalid or missing arguments, reported together with the command's usage.
+    Usage(String),
+    Failed(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) | CliError::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<pico_args::Error> for CliError {
+    fn from(e: pico_args::Error) -> Self {
+        CliError::Usage(e.to_string())
+    }
+}
+
+impl From<String> for CliError {
+    fn from(message: String) -> Self {
+        CliError::Failed(message)
+    }
+}
+
+/// `Ok(false)` if the command ran but a check failed, e.g. a wrong answer or a slower part.
+pub type CommandResult = Result<bool, CliError>;
+
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub usage: &'static str,
+    pub run: fn(Arguments) -> CommandResult,
+}
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "scaffold",
+        about: "Create the module, binary, input and example files of a day",
+        usage: "scaffold <day> [--year <year>] [--example <n>]",
+        run: scaffold::run,
+    },
+    Command {
+        name: "download",
+        about: "Download the input of a day",
+        usage: "download <day> [--year <year>]",
+        run: download::run,
+    },
+    Command {
+        name: "read",
+        about: "Print the puzzle description of a day",
+        usage: "read <day> [--year <year>] [--refresh] [--raw]",
+        run: read::run,
+    },
+    Command {
+        name: "solve",
+        about: "Run a single day, e.g. `solve 5` or `solve 05_sub_optimal`",
+        usage: "solve <day> [--year <year>] [RUN FLAGS]",
+        run: solve::run_solve,
+    },
+    Command {
+        name: "all",
+        about: "Run every registered day of a year",
+        usage: "all [--year <year>] [--days <days>] [RUN FLAGS]",
+        run: solve::run_all,
+    },
+    Command {
+        name: "bench",
+        about: "Benchmark a single day, or every day if none is given",
+        usage: "bench [<day>] [--year <year>] [--days <days>] [RUN FLAGS]",
+        run: solve::run_bench,
+    },
+    Command {
+        name: "submit",
+        about: "Submit the answer of a part, solving it if no answer is given",
+        usage: "submit <day> <part> [<answer>] [--year <year>]",
+        run: submit::run,
+    },
+    Command {
+        name: "readme",
+        about: "Write the stored timings to the benchmark table of README.md",
+        usage: "readme [--run] [--year <year>] [RUN FLAGS]",
+        run: readme::run,
+    },
+    Command {
+        name: "stars",
+        about: "Write the accepted answers to the stars table of README.md",
+        usage: "stars [--year <year>]",
+        run: stars::run,
+    },
+    Command {
+        name: "status",
+        about: "Show the inputs, examples and accepted answers of every day",
+        usage: "status [--year <year>]",
+        run: status::run,
+    },
+];
+
+const RUN_FLAGS: &str = "RUN FLAGS:
+    --input <path>        Read the input from a file, `-` for stdin
+    --example             Run against the example instead of the input
+    --part <1|2>          Only run one part
+    --param <name=value>  Override a puzzle parameter, can be repeated
+    --format <format>     text, json or csv
+    --bench               Benchmark parsing and every part
+    --bench-time <ms>     The time budget of each benchmark
+    --compare             Compare against the saved timings
+    --threshold <percent> The slowdown that counts as a regression
+    --save-timings        Save the timings of this run
+    --accept              Record the answers of this run as correct
+    --overwrite           Let --accept replace accepted answers that differ
+    --timeout <seconds>   Give up on a step after this long, 0 to wait forever";
+
+fn find(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|command| command.name == name)
+}
+
+fn help() -> String {
+    let commands: Vec<String> = COMMANDS
+        .iter()
+        .map(|command| format!("    {:<10}{}", command.name, command.about))
+        .collect();
+    format!(
+        "Usage: aoc <command> [args]\n\nCOMMANDS:\n{}\n    {:<10}Print this help, or the usage of a command\n\nThe year defaults to ${}.",
+        commands.join("\n"),
+        "help",
+        crate::YEAR_ENV
+    )
+}
+
+fn command_help(command: &Command) -> String {
+    let mut help = format!("{}\n\nUsage: aoc {}", command.about, command.usage);
+    if command.usage.contains("RUN FLAGS") {
+        help.push_str("\n\n");
+        help.push_str(RUN_FLAGS);
+    }
+    help
+}
+
+/// Fails if arguments are left over after a command has taken the ones it knows.
+pub fn finish(args: Arguments) -> Result<(), CliError> {
+    let remaining = args.finish();
+    if remaining.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::Usage(format!(
+            "unexpected arguments: {:?}",
+            remaining
+        )))
+    }
+}
+
+/// Exits with the status of a finished command.
+pub fn exit(command: &Command, result: CommandResult) -> ! {
+    match result {
+        Ok(true) => process::exit(0),
+        Ok(false) => process::exit(1),
+        Err(CliError::Failed(message)) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+        Err(CliError::Usage(message)) => {
+            eprintln!("{}\n\nUsage: aoc {}", message, command.usage);
+            process::exit(2);
+        }
+    }
+}
+
+/// The process arguments without `--` separators, which are passed through when a cargo alias
+/// that already ends in `--` is followed by another one, e.g. `cargo status -- --year 2022`.
+fn process_args() -> Arguments {
+    Arguments::from_vec(
+        std::env::args_os()
+            .skip(1)
+            .filter(|arg| arg != "--")
+            .collect(),
+    )
+}
+
+/// Runs a command with the process arguments, as done by the binaries that predate `aoc`.
+pub fn run_command(name: &str) -> ! {
+    let command = find(name).unwrap_or_else(|| panic!("unknown command `{}`", name));
+    run(command, process_args())
+}
+
+/// Runs `solve` for a registered day, as done by the per-day binaries through [`crate::solve!`].
+pub fn solve_day(day: u8, variant: Option<&str>) -> ! {
+    let command = find("solve").expect("`solve` is a command");
+    run_with(command, process_args(), |args| {
+        solve::run_registered(day, variant, args)
+    })
+}
+
+fn run(command: &Command, args: Arguments) -> ! {
+    run_with(command, args, command.run)
+}
+
+fn run_with(
+    command: &Command,
+    mut args: Arguments,
+    run: impl FnOnce(Arguments) -> CommandResult,
+) -> ! {
+    if args.contains(["-h", "--help"]) {
+        println!("{}", command_help(command));
+        process::exit(0);
+    }
+    exit(command, run(args))
+}
+
+/// The entrypoint of the `aoc` binary.
+pub fn main() -> ! {
+    let mut args = process_args();
+    let name: Option<String> = args.subcommand().unwrap_or(None);
+    match name.as_deref() {
+        None => {
+            eprintln!("{}", help());
+            process::exit(2);
+        }
+        Some("help") | Some("-h") | Some("--help") => {
+            let topic: Option<String> = args.opt_free_from_str().unwrap_or(None);
+            match topic.as_deref().map(find) {
+                Some(Some(command)) => println!("{}", command_help(command)),
+                _ => println!("{}", help()),
+            }
+            process::exit(0);
+        }
+        Some(name) => match find(name) {
+            Some(command) => run(command, args),
+            None => {
+                eprintln!("unknown command `{}`\n\n{}", name, help());
+                process::exit(2);
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands() {
+        for name in [
+            "scaffold", "download", "read", "solve", "all", "bench", "submit", "readme", "stars",
+            "status",
+        ] {
+            let command = find(name).unwrap();
+            assert!(command.usage.starts_with(name));
+        }
+        assert!(find("unknown").is_none());
+        assert!(help().contains("status"));
+    }
+}
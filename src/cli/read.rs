@@ -0,0 +1,54 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{CliError, CommandResult};
+use crate::{input, puzzle};
+use pico_args::Arguments;
+use std::fs;
+
+struct Args {
+    day: u8,
+    year: i16,
+    refresh: bool,
+    raw: bool,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, CliError> {
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(crate::default_year);
+    let refresh = args.contains("--refresh");
+    let raw = args.contains("--raw");
+    let day = args.free_from_str()?;
+    super::finish(args)?;
+    Ok(Args {
+        day,
+        year,
+        refresh,
+        raw,
+    })
+}
+
+pub fn run(args: Arguments) -> CommandResult {
+    let args = parse_args(args)?;
+
+    let path = puzzle::puzzle_path(args.year, args.day);
+    let markdown = match fs::read_to_string(input::crate_path(&path)) {
+        Ok(markdown) if !args.refresh => markdown,
+        _ => {
+            eprintln!("Downloading puzzle for day {}, {}...", args.day, args.year);
+            let markdown = puzzle::fetch(args.year, args.day)?;
+            eprintln!("🎄 Saved puzzle to \"{}\".\n", path);
+            markdown
+        }
+    };
+
+    if args.raw {
+        print!("{}", markdown);
+    } else {
+        print!("{}", puzzle::render(&markdown));
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,44 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{CliError, CommandResult};
+use crate::args::RunArgs;
+use crate::bench::BenchConfig;
+use crate::readme::{self, BENCHMARK_MARKER, README_PATH};
+use crate::store::Store;
+use crate::timings::{timings_path, Timings};
+use crate::{days, runner};
+use pico_args::Arguments;
+
+/// `readme [--run]`
+pub fn run(mut args: Arguments) -> CommandResult {
+    let benchmark = args.contains("--run");
+    let mut run_args = RunArgs::parse_flags(&mut args)?;
+    super::finish(args)?;
+
+    let (timings, passed) = if benchmark {
+        let days = days::year(run_args.year)
+            .ok_or_else(|| format!("No days are registered for {}.", run_args.year))?;
+        if run_args.bench.is_none() {
+            run_args.bench = Some(BenchConfig::default());
+        }
+        // the timings of the run are only stored as the new baseline with `--save-timings`.
+        let summary = runner::run_all(days, &run_args)?;
+        (summary.timings, summary.passed)
+    } else if run_args.bench.is_some() || run_args.save_timings {
+        return Err(CliError::Usage(
+            "--bench, --bench-time and --save-timings only apply with --run.".to_string(),
+        ));
+    } else {
+        (Timings::load(timings_path(run_args.year))?, true)
+    };
+
+    let table = readme::benchmark_table(run_args.year, &timings);
+    if readme::update_file(README_PATH, BENCHMARK_MARKER, &table)? {
+        println!("Updated the benchmark table in \"{}\".", README_PATH);
+    } else {
+        println!("The benchmark table in \"{}\" is up to date.", README_PATH);
+    }
+    Ok(passed)
+}
@@ -0,0 +1,345 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{CliError, CommandResult};
+use crate::{input, puzzle};
+use pico_args::Arguments;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+};
+
+const MODULE_TEMPLATE: &str = r###"use crate::solution::Solution;
+
+pub struct Day%DAY_PADDED%;
+
+impl Solution for Day%DAY_PADDED% {
+    const DAY: u8 = %DAY%;
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(_input: &Self::Input<'_>) -> Option<u32> {
+        None
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Option<u32> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", %YEAR%, %DAY%);
+        assert_eq!(Day%DAY_PADDED%::part_one(&Day%DAY_PADDED%::parse(&input)), %PART_ONE_EXPECTED%);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", %YEAR%, %DAY%);
+        assert_eq!(Day%DAY_PADDED%::part_two(&Day%DAY_PADDED%::parse(&input)), %PART_TWO_EXPECTED%);
+    }
+}
+"###;
+
+const BIN_TEMPLATE: &str = r###"fn main() {
+    advent_of_code::solve!(%DAY%);
+}
+"###;
+
+const YEAR_TEMPLATE: &str = r###"/*
+ * Every implemented day of %YEAR% is declared here and listed in `DAYS`.
+ * `cargo scaffold <day> --year %YEAR%` adds new days to both lists.
+ */
+use crate::solution::Day;
+
+pub mod day%DAY_PADDED%;
+
+// one day per line, `cargo scaffold` inserts new days into this list.
+#[rustfmt::skip]
+pub const DAYS: &[Day] = &[
+    Day::new::<day%DAY_PADDED%::Day%DAY_PADDED%>(),
+];
+"###;
+
+const LIB_PATH: &str = "src/lib.rs";
+const YEARS_PATH: &str = "src/days.rs";
+
+struct Args {
+    day: u8,
+    year: i16,
+    /// 1-based index of the code block to use as the example.
+    example: usize,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, CliError> {
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(crate::default_year);
+    let example = args.opt_value_from_str("--example")?.unwrap_or(1);
+    let day = args.free_from_str().map_err(|_| {
+        CliError::Usage(
+            "Need to specify a day (as integer). example: `cargo scaffold 7`".to_string(),
+        )
+    })?;
+    super::finish(args)?;
+    Ok(Args { day, year, example })
+}
+
+/// Loads the stored puzzle description, or downloads it if a session cookie is configured.
+fn load_description(args: &Args) -> Option<String> {
+    let path = puzzle::puzzle_path(args.year, args.day);
+    if let Ok(markdown) = fs::read_to_string(input::crate_path(&path)) {
+        println!("Using puzzle description \"{}\"", &path);
+        return Some(markdown);
+    }
+
+    match puzzle::fetch(args.year, args.day) {
+        Ok(markdown) => {
+            println!("Downloaded puzzle description to \"{}\"", &path);
+            Some(markdown)
+        }
+        Err(e) => {
+            println!("Skipped example extraction: {}", e);
+            None
+        }
+    }
+}
+
+/// The expected value of a generated test, if the example answer fits the template's `u32`.
+fn expected(answer: Option<String>, part: u8) -> String {
+    match answer {
+        Some(answer) => match answer.parse::<u32>() {
+            Ok(answer) => format!("Some({})", answer),
+            Err(_) => {
+                println!(
+                    "Example answer \"{}\" for part {} is not a u32, fill in the test by hand.",
+                    answer, part
+                );
+                "None".to_string()
+            }
+        },
+        None => "None".to_string(),
+    }
+}
+
+// the helpers below take paths relative to the crate root, so scaffolding works from any directory.
+
+fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(input::crate_path(path))
+}
+
+fn create_file(path: &str) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(input::crate_path(path))
+}
+
+fn create_parent_dir(path: &str) -> Result<(), std::io::Error> {
+    match input::crate_path(path).parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+}
+
+fn fill_template(template: &str, year: i16, day: u8) -> String {
+    template
+        .replace("%DAY_PADDED%", &format!("{:02}", day))
+        .replace("%DAY%", &day.to_string())
+        .replace("%YEAR%", &year.to_string())
+}
+
+fn fill_module_template(year: i16, day: u8, description: Option<&str>) -> String {
+    let answer = |part| description.and_then(|markdown| puzzle::expected_answer(markdown, part));
+    fill_template(MODULE_TEMPLATE, year, day)
+        .replace("%PART_ONE_EXPECTED%", &expected(answer(1), 1))
+        .replace("%PART_TWO_EXPECTED%", &expected(answer(2), 2))
+}
+
+/// Inserts `line` into the block of lines starting with `prefix`, keeping the block sorted.
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, line: String) {
+    let block: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+    let position = block
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .unwrap_or_else(|| block.last().map_or(lines.len(), |&i| i + 1));
+    lines.insert(position, line);
+}
+
+fn update_file(path: &str, update: impl FnOnce(&mut Vec<String>)) -> Result<(), std::io::Error> {
+    let mut lines: Vec<String> = fs::read_to_string(input::crate_path(path))?
+        .lines()
+        .map(String::from)
+        .collect();
+    update(&mut lines);
+    fs::write(input::crate_path(path), lines.join("\n") + "\n")
+}
+
+/// Creates the module of a new year with the day in it, and adds the year to `days::YEARS`.
+fn register_year(year: i16, day: u8, registry_path: &str) -> Result<(), std::io::Error> {
+    let module = format!("y{}", year);
+
+    create_parent_dir(registry_path)?;
+    safe_create_file(registry_path)?
+        .write_all(fill_template(YEAR_TEMPLATE, year, day).as_bytes())?;
+
+    update_file(LIB_PATH, |lines| {
+        insert_sorted(lines, "pub mod y", format!("pub mod {};", module))
+    })?;
+    update_file(YEARS_PATH, |lines| {
+        insert_sorted(lines, "use crate::y", format!("use crate::{};", module));
+        insert_sorted(lines, "    (", format!("    ({}, {}::DAYS),", year, module));
+    })
+}
+
+/// Declares the new module in `src/y<year>/mod.rs` and adds it to the `DAYS` registry.
+fn register_day(day_padded: &str, registry_path: &str) -> Result<(), std::io::Error> {
+    let contents = fs::read_to_string(input::crate_path(registry_path))?;
+    let module = format!("day{}", day_padded);
+
+    if contents.contains(&format!("pub mod {};", module)) {
+        return Ok(());
+    }
+
+    let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+    insert_sorted(&mut lines, "pub mod day", format!("pub mod {};", module));
+    insert_sorted(
+        &mut lines,
+        "    Day::new::<day",
+        format!("    Day::new::<{}::Day{}>(),", module, day_padded),
+    );
+
+    fs::write(input::crate_path(registry_path), lines.join("\n") + "\n")
+}
+
+pub fn run(args: Arguments) -> CommandResult {
+    let args = parse_args(args)?;
+    let day = args.day;
+    let year = args.year;
+
+    let day_padded = format!("{:02}", day);
+
+    let input_path = crate::file_path("inputs", year, day);
+    let example_path = crate::file_path("examples", year, day);
+    let registry_path = format!("src/y{}/mod.rs", year);
+    let module_path = format!("src/y{}/day{}.rs", year, day_padded);
+    let bin_path = format!("src/bin/{}.rs", day_padded);
+
+    let description = load_description(&args);
+    let example = description.as_deref().and_then(|markdown| {
+        let examples = puzzle::examples(markdown);
+        if examples.len() < args.example {
+            println!(
+                "Skipped example extraction: the description has {} code block(s), --example {} is out of range.",
+                examples.len(),
+                args.example
+            );
+        }
+        examples.into_iter().nth(args.example.checked_sub(1)?)
+    });
+
+    let is_new_year = !input::crate_path(&registry_path).exists();
+
+    let mut file =
+        match create_parent_dir(&module_path).and_then(|_| safe_create_file(&module_path)) {
+            Ok(file) => file,
+            Err(e) => return Err(format!("Failed to create module file: {}", e).into()),
+        };
+
+    match file.write_all(fill_module_template(year, day, description.as_deref()).as_bytes()) {
+        Ok(_) => {
+            println!("Created module file \"{}\"", &module_path);
+        }
+        Err(e) => return Err(format!("Failed to write module contents: {}", e).into()),
+    }
+
+    if is_new_year {
+        match register_year(year, day, &registry_path) {
+            Ok(_) => {
+                println!(
+                    "Registered year in \"{}\", \"{}\" and \"{}\"",
+                    &registry_path, LIB_PATH, YEARS_PATH
+                );
+            }
+            Err(e) => return Err(format!("Failed to register year: {}", e).into()),
+        }
+    } else {
+        match register_day(&day_padded, &registry_path) {
+            Ok(_) => {
+                println!("Registered day in \"{}\"", &registry_path);
+            }
+            Err(e) => return Err(format!("Failed to register day: {}", e).into()),
+        }
+    }
+
+    // binaries are shared by all years, the year is picked with `--year`.
+    match safe_create_file(&bin_path) {
+        Ok(mut file) => match file.write_all(fill_template(BIN_TEMPLATE, year, day).as_bytes()) {
+            Ok(_) => {
+                println!("Created binary file \"{}\"", &bin_path);
+            }
+            Err(e) => return Err(format!("Failed to write binary contents: {}", e).into()),
+        },
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            println!("Using existing binary file \"{}\"", &bin_path);
+        }
+        Err(e) => return Err(format!("Failed to create binary file: {}", e).into()),
+    }
+
+    match create_parent_dir(&input_path).and_then(|_| create_file(&input_path)) {
+        Ok(_) => {
+            println!("Created empty input file \"{}\"", &input_path);
+        }
+        Err(e) => return Err(format!("Failed to create input file: {}", e).into()),
+    }
+
+    let mut file = match create_parent_dir(&example_path).and_then(|_| create_file(&example_path)) {
+        Ok(file) => file,
+        Err(e) => return Err(format!("Failed to create example file: {}", e).into()),
+    };
+
+    let is_empty = file.metadata().map(|m| m.len() == 0).unwrap_or(false);
+    match example {
+        Some(example) if is_empty => match file.write_all(example.as_bytes()) {
+            Ok(_) => {
+                println!("Created example file \"{}\"", &example_path);
+            }
+            Err(e) => return Err(format!("Failed to write example contents: {}", e).into()),
+        },
+        _ => {
+            println!("Created empty example file \"{}\"", &example_path);
+        }
+    }
+
+    println!("---");
+    if year == crate::default_year() {
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            &day_padded
+        );
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} -- --year {}` to run your solution.",
+            &day_padded, year
+        );
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{CliError, CommandResult};
+use crate::args::RunArgs;
+use crate::bench::BenchConfig;
+use crate::input::INPUT_ENV;
+use crate::output::{self, Format};
+use crate::runner::{self, Runner};
+use crate::solution::{Day, Params};
+use crate::{days, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use pico_args::Arguments;
+
+/// A day as passed on the command line: `5`, `05` or `05_sub_optimal` for a variant.
+fn parse_day(value: &str) -> Result<(u8, Option<String>), String> {
+    let (day, variant) = match value.split_once('_') {
+        Some((day, variant)) => (day, Some(variant.to_string())),
+        None => (value, None),
+    };
+    match day.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok((day, variant)),
+        _ => Err(format!("invalid day `{}`, expected 1 to 25", value)),
+    }
+}
+
+fn parse_run_args(args: &mut Arguments) -> Result<RunArgs, CliError> {
+    let mut run_args = RunArgs::parse_flags(args)?;
+    run_args.apply_env();
+    Ok(run_args)
+}
+
+/// `solve <day>`
+pub fn run_solve(mut args: Arguments) -> CommandResult {
+    let run_args = parse_run_args(&mut args)?;
+    let (day, variant) = args.free_from_fn(parse_day)?;
+    super::finish(args)?;
+    solve(day, variant.as_deref(), &run_args)
+}
+
+/// Runs `solve` for the day of a per-day binary, see [`crate::solve!`].
+pub fn run_registered(day: u8, variant: Option<&str>, mut args: Arguments) -> CommandResult {
+    let run_args = parse_run_args(&mut args)?;
+    super::finish(args)?;
+    solve(day, variant, &run_args)
+}
+
+/// `all`
+pub fn run_all(mut args: Arguments) -> CommandResult {
+    let run_args = parse_run_args(&mut args)?;
+    super::finish(args)?;
+    all(&run_args)
+}
+
+/// `bench [<day>]`, which is `solve` or `all` with `--bench` on by default.
+pub fn run_bench(mut args: Arguments) -> CommandResult {
+    let mut run_args = parse_run_args(&mut args)?;
+    let day = args.opt_free_from_fn(parse_day)?;
+    super::finish(args)?;
+
+    if run_args.bench.is_none() {
+        run_args.bench = Some(BenchConfig::default());
+    }
+    match day {
+        Some((day, variant)) => solve(day, variant.as_deref(), &run_args),
+        None => all(&run_args),
+    }
+}
+
+fn solve(day: u8, variant: Option<&str>, args: &RunArgs) -> CommandResult {
+    let day = match variant {
+        Some(variant) => days::get_variant(args.year, day, variant),
+        None => days::get(args.year, day),
+    };
+    let day = day.ok_or_else(|| {
+        format!(
+            "this day is not registered for {}. Add it to `days::YEARS` or pass another --year.",
+            args.year
+        )
+    })?;
+
+    if args.days.is_some() {
+        return Err(CliError::Usage(
+            "--days can only be used when running all days.".to_string(),
+        ));
+    }
+    Params::new(day.params, &args.params).map_err(CliError::Usage)?;
+
+    let mut runner = Runner::new(args)?;
+    let status = runner.run(day);
+
+    match args.format {
+        Format::Text => runner::print_status(&status),
+        format => output::print_records(format, &output::records(day, &status, args.part)),
+    }
+
+    let passed = runner.finish()?;
+    Ok(passed && !status.failed())
+}
+
+fn all(args: &RunArgs) -> CommandResult {
+    if args.input.is_some() {
+        return Err(CliError::Usage(format!(
+            "--input and ${} can only be used when solving a single day.",
+            INPUT_ENV
+        )));
+    }
+    if !args.params.is_empty() {
+        return Err(CliError::Usage(
+            "--param can only be used when solving a single day.".to_string(),
+        ));
+    }
+    let days = days::year(args.year)
+        .ok_or_else(|| format!("No days are registered for {}.", args.year))?;
+    let days: Vec<Day> = match &args.days {
+        Some(selection) => days
+            .iter()
+            .filter(|day| selection.contains(day.day))
+            .copied()
+            .collect(),
+        None => days.to_vec(),
+    };
+    if days.is_empty() {
+        return Err(CliError::Failed(format!(
+            "None of the selected days are registered for {}.",
+            args.year
+        )));
+    }
+
+    let summary = runner::run_all(&days, args)?;
+
+    if args.format == Format::Text {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            summary.total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
+
+    Ok(summary.passed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("5"), Ok((5, None)));
+        assert_eq!(parse_day("05"), Ok((5, None)));
+        assert_eq!(
+            parse_day("05_sub_optimal"),
+            Ok((5, Some("sub_optimal".to_string())))
+        );
+        assert!(parse_day("26").is_err());
+        assert!(parse_day("five").is_err());
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::CommandResult;
+use crate::answers::{answers_path, Answers};
+use crate::input::crate_path;
+use crate::readme::{self, README_PATH, STARS_MARKER};
+use crate::store::Store;
+use pico_args::Arguments;
+use std::fs;
+
+/// `stars [--year <year>]`
+pub fn run(mut args: Arguments) -> CommandResult {
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(crate::default_year);
+    super::finish(args)?;
+
+    let answers = Answers::load(answers_path(year))?;
+    let contents = fs::read_to_string(crate_path(README_PATH))
+        .map_err(|e| format!("could not read \"{}\": {}", README_PATH, e))?;
+    let current = readme::section(&contents, STARS_MARKER)
+        .map_err(|e| format!("{} in \"{}\"", e, README_PATH))?;
+    let table = readme::stars_table(year, &answers, &current);
+    if readme::update_file(README_PATH, STARS_MARKER, &table)? {
+        println!("Updated the stars table in \"{}\".", README_PATH);
+    } else {
+        println!("The stars table in \"{}\" is up to date.", README_PATH);
+    }
+    Ok(true)
+}
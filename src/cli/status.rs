@@ -0,0 +1,145 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{CliError, CommandResult};
+use crate::answers::{answers_path, input_hash, Answers};
+use crate::input;
+use crate::store::Store;
+use crate::submissions::{submissions_path, Submissions};
+use crate::{days, examples};
+use pico_args::Arguments;
+
+/// What is known about a day, as shown by `status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DayStatus {
+    day: u8,
+    /// The registered implementations: `main` and the names of variants.
+    solutions: Vec<String>,
+    has_input: bool,
+    has_example: bool,
+    parts: [String; 2],
+}
+
+fn part_status(
+    answers: &Answers,
+    submissions: &Submissions,
+    hash: Option<&str>,
+    day: u8,
+    part: u8,
+) -> String {
+    if let Some(answer) = hash.and_then(|hash| answers.get(day, hash, part)) {
+        return format!("★ {}", answer.lines().next().unwrap_or_default());
+    }
+    match submissions.attempts(day, part) {
+        [] => "-".to_string(),
+        attempts => format!(
+            "{} attempt(s), last: {}",
+            attempts.len(),
+            attempts[attempts.len() - 1].outcome
+        ),
+    }
+}
+
+fn collect(year: i16, answers: &Answers, submissions: &Submissions) -> Vec<DayStatus> {
+    let registered = days::year(year).unwrap_or_default();
+    (1..=25)
+        .map(|day| {
+            let solutions: Vec<String> = registered
+                .iter()
+                .filter(|d| d.day == day)
+                .map(|d| d.variant.unwrap_or("main").to_string())
+                .collect();
+            let input = crate::try_read_file("inputs", year, day).ok();
+            let hash = input.as_deref().map(input_hash);
+            let has_example = input::crate_path(crate::file_path("examples", year, day)).exists()
+                || input::crate_path(examples::expected_path(year, day)).exists();
+            DayStatus {
+                day,
+                solutions,
+                has_input: input.is_some(),
+                has_example,
+                parts: [1, 2]
+                    .map(|part| part_status(answers, submissions, hash.as_deref(), day, part)),
+            }
+        })
+        .filter(|status| {
+            !status.solutions.is_empty()
+                || status.has_input
+                || status.parts.iter().any(|p| p != "-")
+        })
+        .collect()
+}
+
+/// `status`
+pub fn run(mut args: Arguments) -> CommandResult {
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(crate::default_year);
+    super::finish(args)?;
+
+    let answers = Answers::load(answers_path(year))?;
+    let submissions = Submissions::load(submissions_path(year))?;
+    let statuses = collect(year, &answers, &submissions);
+    if statuses.is_empty() {
+        return Err(CliError::Failed(format!(
+            "Nothing has been started for {} yet.",
+            year
+        )));
+    }
+
+    let yes_no = |value: bool| if value { "yes" } else { "no" };
+    println!(
+        "{:<5}{:<24}{:<7}{:<9}{:<28}Part 2",
+        "Day", "Solutions", "Input", "Example", "Part 1"
+    );
+    for status in &statuses {
+        let solutions = if status.solutions.is_empty() {
+            "-".to_string()
+        } else {
+            status.solutions.join(", ")
+        };
+        println!(
+            "{:<5}{:<24}{:<7}{:<9}{:<28}{}",
+            format!("{:02}", status.day),
+            solutions,
+            yes_no(status.has_input),
+            yes_no(status.has_example),
+            status.parts[0],
+            status.parts[1]
+        );
+    }
+
+    let stars = statuses
+        .iter()
+        .flat_map(|status| &status.parts)
+        .filter(|part| part.starts_with('★'))
+        .count();
+    println!("---");
+    println!("{} star(s) for {}.", stars, year);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::submissions::{Attempt, SubmitOutcome};
+
+    #[test]
+    fn test_part_status() {
+        let mut answers = Answers::default();
+        answers.insert(1, "abc", 1, "24000");
+        let mut submissions = Submissions::default();
+        submissions.record(1, 2, Attempt::new("100", SubmitOutcome::TooLow, ""));
+
+        assert_eq!(
+            part_status(&answers, &submissions, Some("abc"), 1, 1),
+            "★ 24000"
+        );
+        assert_eq!(
+            part_status(&answers, &submissions, Some("abc"), 1, 2),
+            "1 attempt(s), last: too low"
+        );
+        assert_eq!(part_status(&answers, &submissions, None, 1, 1), "-");
+    }
+}
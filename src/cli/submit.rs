@@ -0,0 +1,189 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{CliError, CommandResult};
+use crate::answer::Answer;
+use crate::answers::{answers_path, input_hash, Answers};
+use crate::args::DEFAULT_TIMEOUT_SECS;
+use crate::client::{self, Client};
+use crate::days;
+use crate::runner::{self, Outcome, RunOptions};
+use crate::solution::Params;
+use crate::store::Store;
+use crate::submissions::{submissions_path, Attempt, Rejection, Submissions, SubmitOutcome};
+use pico_args::Arguments;
+use std::time::Duration;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: i16,
+}
+
+fn parse_args(mut args: Arguments) -> Result<Args, CliError> {
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(crate::default_year);
+    let parsed = Args {
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        answer: args.opt_free_from_str()?,
+        year,
+    };
+    super::finish(args)?;
+    if !(1..=25).contains(&parsed.day) {
+        return Err(CliError::Usage(format!(
+            "day must be between 1 and 25, got {}",
+            parsed.day
+        )));
+    }
+    if !(1..=2).contains(&parsed.part) {
+        return Err(CliError::Usage(format!(
+            "part must be 1 or 2, got {}",
+            parsed.part
+        )));
+    }
+    Ok(parsed)
+}
+
+pub fn run(args: Arguments) -> CommandResult {
+    let args = parse_args(args)?;
+
+    let input = crate::try_read_file("inputs", args.year, args.day).ok();
+    // `try_read_file` normalizes line endings, like the input hash of the runner.
+    let hash = input.as_deref().map(input_hash);
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let day = days::get(args.year, args.day).ok_or_else(|| {
+                format!(
+                    "day {} is not registered for {}, pass the answer explicitly.",
+                    args.day, args.year
+                )
+            })?;
+            // solved on the raw input, so the day's own normalization policy applies.
+            let raw = crate::file_source("inputs", args.year, args.day)
+                .read()
+                .map_err(|e| format!("Missing input: {}", e))?;
+            let input = day.prepare(&raw);
+            let options = RunOptions {
+                part: Some(args.part),
+                timeout: Some(Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
+                ..RunOptions::default()
+            };
+            let (_, parts) = runner::without_panic_output(|| {
+                runner::run_day(day, &input, &Params::defaults(day.params), &options)
+            });
+            let outcome = parts.into_iter().next().map(|result| result.outcome);
+            match outcome {
+                Some(Outcome::Solved(Answer::Grid(grid))) => {
+                    return Err(CliError::Failed(format!(
+                        "part {} returned a picture, pass the letters it shows explicitly:\n{}",
+                        args.part, grid
+                    )))
+                }
+                Some(Outcome::Solved(answer)) => answer.to_string(),
+                Some(Outcome::Panicked(message)) => {
+                    return Err(CliError::Failed(format!(
+                        "part {} panicked: {}",
+                        args.part, message
+                    )))
+                }
+                Some(Outcome::TimedOut(timeout)) => {
+                    return Err(CliError::Failed(format!(
+                        "part {} timed out after {:.2?}.",
+                        args.part, timeout
+                    )))
+                }
+                Some(Outcome::Skipped(reason)) => {
+                    return Err(CliError::Failed(format!(
+                        "part {} was skipped, {}.",
+                        args.part, reason
+                    )))
+                }
+                Some(Outcome::Unsolved) | None => {
+                    return Err(CliError::Failed(format!(
+                        "part {} is not solved yet.",
+                        args.part
+                    )))
+                }
+            }
+        }
+    };
+
+    let answers_path = answers_path(args.year);
+    let submissions_path = submissions_path(args.year);
+    let mut answers = Answers::load(&answers_path)?;
+    let mut submissions = Submissions::load(&submissions_path)?;
+
+    if let Some(accepted) = hash
+        .as_deref()
+        .and_then(|hash| answers.get(args.day, hash, args.part))
+    {
+        if *accepted == answer {
+            println!(
+                "{} is already the accepted answer, nothing to submit.",
+                answer
+            );
+            return Ok(true);
+        }
+        return Err(CliError::Failed(format!(
+            "{} was already accepted for this part, refusing to submit {}.",
+            accepted, answer
+        )));
+    }
+
+    // resubmitting a known wrong answer only adds to the lockout, an answer out of bounds may
+    // still be worth a try.
+    match submissions.check(args.day, args.part, &answer) {
+        Err(rejection @ Rejection::KnownWrong(_)) => {
+            return Err(CliError::Failed(format!(
+                "Refusing to submit {}: {}.",
+                answer, rejection
+            )))
+        }
+        Err(rejection) => eprintln!("Warning: {}.", rejection),
+        Ok(()) => {}
+    }
+
+    let session = client::session()?;
+    println!(
+        "Submitting {} for day {}, part {} ({})...",
+        answer, args.day, args.part, args.year
+    );
+    let message = Client::new(&session).submit(args.year, args.day, args.part, &answer)?;
+    let outcome = SubmitOutcome::from_response(&message);
+
+    submissions.record(
+        args.day,
+        args.part,
+        Attempt::new(&answer, outcome, &message),
+    );
+    if let Err(e) = submissions.save(&submissions_path) {
+        eprintln!("could not save \"{}\": {}", submissions_path, e);
+    }
+
+    if outcome == SubmitOutcome::Correct {
+        match hash {
+            Some(hash) => {
+                answers.insert(args.day, &hash, args.part, &answer);
+                if let Err(e) = answers.save(&answers_path) {
+                    eprintln!("could not save \"{}\": {}", answers_path, e);
+                }
+            }
+            None => {
+                eprintln!("Input is missing, so the answer was not added to the known answers.")
+            }
+        }
+    }
+
+    println!("---");
+    println!("{}", message);
+    println!("---");
+    println!("Outcome: {}", outcome);
+
+    Ok(outcome == SubmitOutcome::Correct)
+}
@@ -0,0 +1,320 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the Advent of Code servers, as requested by its maintainer.
+/// Points to the `repository` set in `Cargo.toml`, so requests can be traced back to it.
+pub fn user_agent() -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    match env!("CARGO_PKG_REPOSITORY") {
+        "" => format!("advent_of_code/{}", version),
+        repository => format!("advent_of_code/{} (+{})", version, repository),
+    }
+}
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Reads the session cookie from `$AOC_SESSION` or `~/.adventofcode.session`.
+pub fn session() -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .ok_or("could not determine the home directory to read the session cookie from")?;
+    let path = PathBuf::from(home).join(SESSION_FILE);
+
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(format!(
+            "no session cookie found. Set ${} or paste it into \"{}\".",
+            SESSION_ENV,
+            path.display()
+        )),
+    }
+}
+
+/// A minimal HTTP client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(session: &str) -> Self {
+        Client::with_base_url(AOC_URL, session)
+    }
+
+    /// Creates a client against another server, e.g. a local stand-in in tests.
+    pub fn with_base_url(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(&user_agent())
+                .redirects(0)
+                .build(),
+        }
+    }
+
+    fn get(&self, url: &str) -> Result<String, String> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_body(url, response)
+    }
+
+    /// Downloads the puzzle input for a day.
+    pub fn input(&self, year: i16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let body = self.get(&url)?;
+        if is_html(&body) {
+            return Err(format!(
+                "{} returned a web page instead of a puzzle input. Is the session cookie still valid?",
+                url
+            ));
+        }
+        if body.trim().is_empty() {
+            return Err(format!("{} returned an empty puzzle input.", url));
+        }
+        Ok(body)
+    }
+
+    /// Downloads the puzzle page for a day. It includes part two once part one is solved.
+    pub fn puzzle(&self, year: i16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        let body = self.get(&url)?;
+        if !body.contains("<article") {
+            return Err(format!("{} does not contain a puzzle description.", url));
+        }
+        Ok(body)
+    }
+
+    /// Submits an answer and returns the text of the response's `<article>`.
+    pub fn submit(&self, year: i16, day: u8, part: u8, answer: &str) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let body = read_body(&url, response)?;
+        Ok(article_text(&body).unwrap_or(body))
+    }
+}
+
+fn read_body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+    match response {
+        Ok(response) if response.status() == 200 => response
+            .into_string()
+            .map_err(|e| format!("could not read response from {}: {}", url, e)),
+        Ok(response) => Err(format!(
+            "unexpected response from {}: {} {}. Is the session cookie still valid?",
+            url,
+            response.status(),
+            response.status_text()
+        )),
+        Err(ureq::Error::Status(status, response)) => {
+            // error pages such as "Please log in to get your puzzle input." are plain text.
+            let reason = response
+                .into_string()
+                .ok()
+                .filter(|body| !is_html(body) && !body.trim().is_empty())
+                .map(|body| format!(": {}", body.trim()))
+                .unwrap_or_default();
+            Err(format!(
+                "request to {} failed with status {}{}",
+                url, status, reason
+            ))
+        }
+        Err(e) => Err(format!("request to {} failed: {}", url, e)),
+    }
+}
+
+/// Whether a response is a web page, such as the login page, rather than plain text.
+fn is_html(body: &str) -> bool {
+    let start = body.trim_start().to_lowercase();
+    start.starts_with("<!doctype") || start.starts_with("<html")
+}
+
+/// Extracts the text content of the first `<article>` element of a page.
+fn article_text(html: &str) -> Option<String> {
+    let start = html.find("<article")?;
+    let end = html[start..].find("</article>")? + start;
+    Some(strip_tags(&html[start..end]).trim().to_string())
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text)
+}
+
+pub(crate) fn decode_entities(text: &str) -> String {
+    text.replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// A local stand-in for the Advent of Code server that serves canned responses.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// Serves one response per request, in order, and sends every received request back.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8_lossy(&body_bytes));
+                sender.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 {} Status\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) = mock::serve(vec![(
+            200,
+            "<html><main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main></html>",
+        )]);
+
+        let client = Client::with_base_url(&url, "abc123");
+        let text = client.submit(2022, 7, 2, "24933642").unwrap();
+        assert_eq!(
+            text,
+            "That's the right answer! You are one gold star closer."
+        );
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2022/day/7/answer "));
+        assert!(request.contains("Cookie: session=abc123"));
+        assert!(request.contains(&format!("User-Agent: {}", user_agent())));
+        assert!(request.ends_with("level=2&answer=24933642"));
+    }
+
+    #[test]
+    fn test_submit_error_status() {
+        let (url, _requests) = mock::serve(vec![(500, "Internal Server Error")]);
+        let client = Client::with_base_url(&url, "expired");
+        assert!(client.submit(2022, 1, 1, "1").unwrap_err().contains("500"));
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, requests) = mock::serve(vec![(200, "1000\n2000\n\n4000\n")]);
+
+        let client = Client::with_base_url(&url, "abc123");
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n\n4000\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input "));
+        assert!(request.contains("Cookie: session=abc123"));
+        assert!(request.contains(&format!("User-Agent: {}", user_agent())));
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let (url, requests) = mock::serve(vec![
+            (
+                200,
+                "<main><article><h2>--- Day 1 ---</h2></article></main>",
+            ),
+            (
+                200,
+                "<main>Please don't repeatedly request this endpoint.</main>",
+            ),
+        ]);
+        let client = Client::with_base_url(&url, "abc123");
+
+        assert!(client.puzzle(2022, 1).unwrap().contains("--- Day 1 ---"));
+        assert!(requests.recv().unwrap().starts_with("GET /2022/day/1 "));
+        assert!(client.puzzle(2022, 1).is_err());
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (url, _requests) = mock::serve(vec![
+            (
+                200,
+                "<!DOCTYPE html>\n<html><body><a href=\"/2022/auth/login\">Log In</a></body></html>",
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            (200, ""),
+        ]);
+        let client = Client::with_base_url(&url, "expired");
+
+        assert!(client.input(2022, 1).unwrap_err().contains("web page"));
+        assert!(client
+            .input(2022, 1)
+            .unwrap_err()
+            .contains("400: Puzzle inputs differ by user."));
+        assert!(client.input(2022, 1).unwrap_err().contains("empty"));
+    }
+}
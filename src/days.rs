@@ -0,0 +1,34 @@
+/*
+ * Every year is declared in `lib.rs` and listed in `YEARS`.
+ * `cargo scaffold <day> --year <year>` adds new years to both lists.
+ */
+use crate::solution::Day;
+use crate::y2022;
+
+// one year per line, `cargo scaffold` inserts new years into this list.
+#[rustfmt::skip]
+pub const YEARS: &[(i16, &[Day])] = &[
+    (2022, y2022::DAYS),
+];
+
+/// Looks up the registered days of a year.
+pub fn year(year: i16) -> Option<&'static [Day]> {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map(|(_, days)| *days)
+}
+
+/// Looks up the main implementation of a day.
+pub fn get(year: i16, day: u8) -> Option<&'static Day> {
+    self::year(year)?
+        .iter()
+        .find(|d| d.day == day && d.variant.is_none())
+}
+
+/// Looks up an alternative implementation of a day by its variant name.
+pub fn get_variant(year: i16, day: u8, variant: &str) -> Option<&'static Day> {
+    self::year(year)?
+        .iter()
+        .find(|d| d.day == day && d.variant == Some(variant))
+}
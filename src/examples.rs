@@ -0,0 +1,189 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answer::Answer;
+use crate::input;
+use crate::solution::{Day, Params, Solution};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+
+/// The file of a named example, e.g. `src/examples/2022/09-b.txt`.
+pub fn example_path(year: i16, day: u8, name: &str) -> String {
+    format!("src/examples/{}/{:02}-{}.txt", year, day, name)
+}
+
+/// The expected answers of every named example of a day, e.g. `src/examples/2022/09.json`.
+pub fn expected_path(year: i16, day: u8) -> String {
+    format!("src/examples/{}/{:02}.json", year, day)
+}
+
+/// The declared answers of one example. Parts without an answer are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    /// Overrides of the day's puzzle parameters that apply to this example.
+    #[serde(default)]
+    pub params: BTreeMap<String, i64>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            _ => self.part_two.as_ref(),
+        }
+    }
+}
+
+/// Loads the declared answers of a day, keyed by example name.
+pub fn load(year: i16, day: u8) -> Result<BTreeMap<String, Expected>, String> {
+    let path = expected_path(year, day);
+    let contents = fs::read_to_string(input::crate_path(&path))
+        .map_err(|e| format!("could not read \"{}\": {}", path, e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("could not parse \"{}\": {}", path, e))
+}
+
+/// The example a day is run against with `--example`.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub source: input::Source,
+    /// Empty for an unnamed example, whose answers cannot be declared.
+    pub expected: Expected,
+}
+
+impl Example {
+    /// The example's parameter overrides, in the form passed to [`Params::new`].
+    pub fn overrides(&self) -> Vec<(String, i64)> {
+        self.expected
+            .params
+            .iter()
+            .map(|(name, value)| (name.clone(), *value))
+            .collect()
+    }
+}
+
+/// The unnamed example `src/examples/<year>/<day>.txt` if it exists, otherwise the first named example.
+pub fn default_example(year: i16, day: u8) -> Result<Example, String> {
+    let path = input::crate_path(format!("src/examples/{}/{:02}.txt", year, day));
+    if path.exists() {
+        return Ok(Example {
+            source: input::Source::File(path),
+            expected: Expected::default(),
+        });
+    }
+
+    let declarations = load(year, day)?;
+    let (name, expected) = declarations.into_iter().next().ok_or_else(|| {
+        format!(
+            "no examples are declared in \"{}\"",
+            expected_path(year, day)
+        )
+    })?;
+    Ok(Example {
+        source: input::Source::File(input::crate_path(example_path(year, day, &name))),
+        expected,
+    })
+}
+
+/// Runs one part of a day against a named example and compares it with the declared answer.
+fn check(day: &Day, year: i16, name: &str, part: u8, expected: &Expected) -> Result<(), String> {
+    let answer = match expected.part(part) {
+        Some(answer) => answer,
+        None => return Ok(()),
+    };
+
+    let path = example_path(year, day.day, name);
+    let example = Example {
+        source: input::Source::File(input::crate_path(&path)),
+        expected: expected.clone(),
+    };
+    let raw = example.source.read().map_err(|e| e.to_string())?;
+    let params = Params::new(day.params, &example.overrides())
+        .map_err(|e| format!("{} in \"{}\"", e, expected_path(year, day.day)))?;
+
+    let input = day.prepare(&raw);
+    let actual = match part {
+        1 => day.part_one(&input, &params),
+        _ => day.part_two(&input, &params),
+    };
+    let actual = actual.map(|answer| answer.to_string());
+    if actual.as_deref() == Some(answer.to_string().as_str()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "part {} of example \"{}\": expected {}, got {}",
+            part,
+            path,
+            answer,
+            actual.unwrap_or_else(|| "no answer".to_string())
+        ))
+    }
+}
+
+/// Runs a solution against every example declared in `src/examples/<year>/<day>.json` and checks
+/// each declared answer. Used by [`crate::example_tests`], panics listing every mismatch.
+pub fn check_all<S: Solution>(year: i16) {
+    let day = Day::new::<S>();
+    let path = expected_path(year, day.day);
+    let declarations = load(year, day.day).unwrap_or_else(|e| panic!("{}", e));
+    assert!(
+        !declarations.is_empty(),
+        "no examples are declared in \"{}\"",
+        path
+    );
+
+    let mut failures = vec![];
+    for (name, expected) in &declarations {
+        if expected.part_one.is_none() && expected.part_two.is_none() {
+            failures.push(format!(
+                "example `{}` declares no answers in \"{}\"",
+                name, path
+            ));
+        }
+        for part in 1..=2 {
+            if let Err(e) = check(&day, year, name, part, expected) {
+                failures.push(e);
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Generates a test that checks every example and part declared in `src/examples/<year>/<day>.json`.
+///
+/// ```ignore
+/// example_tests!(Day09, 2022);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $year:expr) => {
+        #[test]
+        fn test_examples() {
+            $crate::examples::check_all::<$solution>($year);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected() {
+        let declarations: BTreeMap<String, Expected> = serde_json::from_str(
+            r#"{ "a": { "part_one": 13, "params": { "row": 10 } }, "b": { "part_two": "CMZ" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(declarations["a"].part(1), Some(&Answer::Number(13)));
+        assert_eq!(declarations["a"].part(2), None);
+        assert_eq!(declarations["a"].params["row"], 10);
+        assert!(declarations["b"].params.is_empty());
+        assert_eq!(declarations["b"].part(2).unwrap().to_string(), "CMZ");
+        assert!(serde_json::from_str::<Expected>(r#"{ "part_three": 1 }"#).is_err());
+    }
+}
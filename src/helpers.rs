@@ -2,3 +2,173 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+
+/// The 4x6 letters of puzzles such as 2022 day 10, drawn with `#` for lit pixels.
+#[rustfmt::skip]
+const SMALL_FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The 6x10 letters of puzzles such as 2018 day 10.
+#[rustfmt::skip]
+const LARGE_FONT: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Reads the block letters shown in rows of `#` (lit) and `.` (dark) pixels, as rendered by puzzles
+/// whose answer is a picture. Supports the 4x6 and the 6x10 font, picked by the number of rows.
+pub fn ocr<S: AsRef<str>>(rows: &[S]) -> Result<String, String> {
+    let pixels: Vec<Vec<bool>> = rows
+        .iter()
+        .map(|row| row.as_ref().chars().map(|c| c == '#').collect())
+        .collect();
+    let font: Vec<(char, Vec<String>)> = match pixels.len() {
+        6 => SMALL_FONT
+            .iter()
+            .map(|(c, glyph)| (*c, trim(glyph)))
+            .collect(),
+        10 => LARGE_FONT
+            .iter()
+            .map(|(c, glyph)| (*c, trim(glyph)))
+            .collect(),
+        height => return Err(format!("expected 6 or 10 rows of letters, got {}", height)),
+    };
+
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |col: usize| pixels.iter().any(|row| row.get(col) == Some(&true));
+
+    // letters are separated by at least one dark column.
+    let mut glyphs = vec![];
+    let mut col = 0;
+    while col < width {
+        if !lit(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < width && lit(col) {
+            col += 1;
+        }
+        glyphs.push((start, col - 1));
+    }
+    if glyphs.is_empty() {
+        return Err("no letters found, every pixel is dark".to_string());
+    }
+
+    let mut text = String::new();
+    let mut unrecognized = vec![];
+    for (start, end) in glyphs {
+        let glyph: Vec<String> = pixels
+            .iter()
+            .map(|row| {
+                (start..=end)
+                    .map(|col| {
+                        if row.get(col) == Some(&true) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        match font.iter().find(|(_, known)| *known == glyph) {
+            Some((letter, _)) => text.push(*letter),
+            None if start == end => unrecognized.push(start.to_string()),
+            None => unrecognized.push(format!("{}-{}", start, end)),
+        }
+    }
+
+    if unrecognized.is_empty() {
+        Ok(text)
+    } else {
+        Err(format!(
+            "unrecognized letters at columns {}",
+            unrecognized.join(", ")
+        ))
+    }
+}
+
+/// Removes the dark columns on either side of a glyph.
+fn trim(glyph: &[&str]) -> Vec<String> {
+    let lit = |col: usize| glyph.iter().any(|row| row.as_bytes()[col] == b'#');
+    let width = glyph[0].len();
+    let start = (0..width).find(|&col| lit(col)).unwrap_or(0);
+    let end = (0..width).rev().find(|&col| lit(col)).unwrap_or(0);
+    glyph
+        .iter()
+        .map(|row| row[start..=end].to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render<const H: usize>(
+        font: &[(char, [&str; H])],
+        text: &str,
+        spacing: &str,
+    ) -> Vec<String> {
+        (0..H)
+            .map(|row| {
+                text.chars()
+                    .map(|c| font.iter().find(|(letter, _)| *letter == c).unwrap().1[row])
+                    .collect::<Vec<_>>()
+                    .join(spacing)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ocr() {
+        let small: String = SMALL_FONT.iter().map(|(c, _)| c).collect();
+        assert_eq!(ocr(&render(&SMALL_FONT, &small, ".")), Ok(small));
+
+        let large: String = LARGE_FONT.iter().map(|(c, _)| c).collect();
+        assert_eq!(ocr(&render(&LARGE_FONT, &large, "..")), Ok(large));
+    }
+
+    #[test]
+    fn test_ocr_errors() {
+        let mut rows = render(&SMALL_FONT, "HI", ".");
+        rows[0].push_str(".#..#");
+        assert_eq!(
+            ocr(&rows),
+            Err("unrecognized letters at columns 10, 13".to_string())
+        );
+        assert!(ocr(&["#..#", "####"]).is_err());
+        assert!(ocr(&["...."; 6]).is_err());
+    }
+}
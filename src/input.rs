@@ -0,0 +1,178 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::borrow::Cow;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Overrides the input of a day, like `--input <path>`. `-` reads from stdin.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Resolves a path relative to the crate root, so loading works from any working directory.
+pub fn crate_path(path: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+/// How an input is cleaned up before it is parsed, see [`crate::solution::Solution::NORMALIZE`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Normalize {
+    /// The input exactly as stored, for days where every byte matters.
+    Raw,
+    /// Converts `\r\n` line endings to `\n`, so inputs saved on Windows parse like any other.
+    #[default]
+    LineEndings,
+    /// Also strips trailing whitespace from every line, trailing blank lines and the final newline.
+    Trim,
+}
+
+/// Applies a policy to an input, borrowing it when nothing needs to change.
+pub fn normalize(input: &str, policy: Normalize) -> Cow<'_, str> {
+    match policy {
+        Normalize::Raw => Cow::Borrowed(input),
+        Normalize::LineEndings if !input.contains("\r\n") => Cow::Borrowed(input),
+        Normalize::LineEndings => Cow::Owned(input.replace("\r\n", "\n")),
+        Normalize::Trim => Cow::Owned(
+            input
+                .lines()
+                .map(str::trim_end)
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim_end()
+                .to_string(),
+        ),
+    }
+}
+
+/// Where the input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Parses an override given on the command line or in `$AOC_INPUT`.
+    /// Relative paths are resolved from the working directory, like any other CLI path.
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Reads the untouched input, treating a missing or blank input as an error.
+    pub fn read_bytes(&self) -> Result<Vec<u8>, InputError> {
+        let input = match self {
+            Source::File(path) => fs::read(path),
+            Source::Stdin => {
+                let mut input = vec![];
+                io::stdin().read_to_end(&mut input).map(|_| input)
+            }
+        }
+        .map_err(|e| InputError::Unreadable(self.clone(), e))?;
+
+        if input.iter().all(u8::is_ascii_whitespace) {
+            return Err(InputError::Empty(self.clone()));
+        }
+        Ok(input)
+    }
+
+    /// Reads the input as text without normalizing it, see [`Source::read_bytes`].
+    pub fn read(&self) -> Result<String, InputError> {
+        String::from_utf8(self.read_bytes()?).map_err(|e| {
+            InputError::Unreadable(self.clone(), io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "\"{}\"", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Unreadable(Source, io::Error),
+    Empty(Source),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Unreadable(source, e) if e.kind() == io::ErrorKind::NotFound => {
+                write!(f, "{} does not exist", source)
+            }
+            InputError::Unreadable(source, e) => write!(f, "could not read {}: {}", source, e),
+            InputError::Empty(source) => write!(f, "{} is empty", source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crate_path() {
+        assert!(crate_path("Cargo.toml").is_file());
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("aoc_input_{}.txt", std::process::id()));
+
+        let missing = Source::File(path.clone()).read().unwrap_err();
+        assert_eq!(
+            missing.to_string(),
+            format!("\"{}\" does not exist", path.display())
+        );
+
+        fs::write(&path, " \n").unwrap();
+        let empty = Source::File(path.clone()).read().unwrap_err();
+        assert_eq!(
+            empty.to_string(),
+            format!("\"{}\" is empty", path.display())
+        );
+
+        fs::write(&path, "1\n2\n").unwrap();
+        assert_eq!(Source::File(path.clone()).read().unwrap(), "1\n2\n");
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_normalize() {
+        let input = "    [D]  \r\n[N] [C]\r\n\r\n move 1\r\n\r\n";
+
+        assert_eq!(normalize(input, Normalize::Raw), input);
+        assert_eq!(
+            normalize(input, Normalize::LineEndings),
+            "    [D]  \n[N] [C]\n\n move 1\n\n"
+        );
+        assert_eq!(
+            normalize(input, Normalize::Trim),
+            "    [D]\n[N] [C]\n\n move 1"
+        );
+        assert!(matches!(
+            normalize("1\n2\n", Normalize::LineEndings),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Source::parse("-"), Source::Stdin);
+        assert_eq!(
+            Source::parse("in.txt"),
+            Source::File(PathBuf::from("in.txt"))
+        );
+    }
+}
@@ -3,123 +3,81 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use input::{InputError, Normalize, Source};
 use std::env;
-use std::fs;
 
+pub mod answer;
+pub mod answers;
+pub mod args;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod days;
+pub mod examples;
 pub mod helpers;
+pub mod input;
+pub mod memory;
+pub mod output;
+pub mod puzzle;
+pub mod readme;
+pub mod runner;
+pub mod solution;
+pub mod store;
+pub mod submissions;
+pub mod timings;
+pub mod y2022;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+pub const DEFAULT_YEAR: i16 = 2022;
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// Solves a registered day against its puzzle input, e.g. `solve!(1)` or `solve!(5, "sub_optimal")`.
+/// The year is taken from `--year`, defaulting to [`default_year`].
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+    ($day:expr) => {{
+        $crate::solve_day($day, None);
+    }};
+    ($day:expr, $variant:expr) => {{
+        $crate::solve_day($day, Some($variant));
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
-
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn solve_day(day: u8, variant: Option<&str>) {
+    cli::solve_day(day, variant);
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// The year to work on when no `--year` is passed: `$AOC_YEAR`, which is set in `.cargo/config`.
+pub fn default_year() -> i16 {
+    env::var(YEAR_ENV)
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .unwrap_or(DEFAULT_YEAR)
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+pub fn file_path(folder: &str, year: i16, day: u8) -> String {
+    format!("src/{}/{}/{:02}.txt", folder, year, day)
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// The file of a day, relative to the crate root.
+pub fn file_source(folder: &str, year: i16, day: u8) -> Source {
+    Source::File(input::crate_path(file_path(folder, year, day)))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+/// Reads a file of a day with normalized line endings. Missing and empty files are errors.
+pub fn try_read_file(folder: &str, year: i16, day: u8) -> Result<String, InputError> {
+    let input = file_source(folder, year, day).read()?;
+    Ok(input::normalize(&input, Normalize::LineEndings).into_owned())
+}
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+/// Reads a file of a day untouched, for days that need the raw bytes.
+pub fn try_read_bytes(folder: &str, year: i16, day: u8) -> Result<Vec<u8>, InputError> {
+    file_source(folder, year, day).read_bytes()
+}
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
-    }
+pub fn read_file(folder: &str, year: i16, day: u8) -> String {
+    try_read_file(folder, year, day).unwrap_or_else(|e| panic!("{}", e))
 }
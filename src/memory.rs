@@ -0,0 +1,196 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Heap usage of a single step, as counted by [`CountingAllocator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Memory {
+    pub allocations: usize,
+    /// The total size of all allocations, including memory that was freed again.
+    pub allocated: usize,
+    /// The most heap in use at once, on top of what was in use before the step.
+    pub peak: usize,
+}
+
+impl fmt::Display for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.allocated),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.2} {}", value, UNITS[unit]),
+    }
+}
+
+/// Wraps the system allocator and counts allocations, allocated bytes and the peak heap size.
+/// Installed as the global allocator with the `count-allocations` feature.
+pub struct CountingAllocator {
+    allocations: AtomicUsize,
+    allocated: AtomicUsize,
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> Self {
+        CountingAllocator {
+            allocations: AtomicUsize::new(0),
+            allocated: AtomicUsize::new(0),
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    /// Runs `func` and returns the heap usage while it ran. Counts are shared by all threads.
+    pub fn measure<T>(&self, func: impl FnOnce() -> T) -> (T, Memory) {
+        let allocations = self.allocations.load(Ordering::Relaxed);
+        let allocated = self.allocated.load(Ordering::Relaxed);
+        let current = self.current.load(Ordering::Relaxed);
+        self.peak.store(current, Ordering::Relaxed);
+
+        let result = func();
+
+        let memory = Memory {
+            allocations: self.allocations.load(Ordering::Relaxed) - allocations,
+            allocated: self.allocated.load(Ordering::Relaxed) - allocated,
+            peak: self.peak.load(Ordering::Relaxed).saturating_sub(current),
+        };
+        (result, memory)
+    }
+
+    fn record(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.allocated.fetch_add(size, Ordering::Relaxed);
+        let current = self.current.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.current.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Counted as freeing the old block and allocating one of the new size.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.current.fetch_sub(layout.size(), Ordering::Relaxed);
+            self.record(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+static DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// Stops measuring for the rest of the process, once a timed-out step is left running on
+/// another thread. Its allocations would otherwise be counted as those of later steps.
+pub fn disable() {
+    DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// Runs `func`, measuring its heap usage if the crate is built with the `count-allocations` feature.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<Memory>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        if DISABLED.load(Ordering::Relaxed) {
+            return (func(), None);
+        }
+        let (result, memory) = ALLOCATOR.measure(func);
+        (result, Some(memory))
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (func(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let allocator = CountingAllocator::new();
+        let small = Layout::from_size_align(16, 8).unwrap();
+        let large = Layout::from_size_align(1024, 8).unwrap();
+
+        let (_, memory) = allocator.measure(|| unsafe {
+            let a = allocator.alloc(small);
+            let b = allocator.alloc(large);
+            allocator.dealloc(b, large);
+            let a = allocator.realloc(a, small, 64);
+            allocator.dealloc(a, Layout::from_size_align(64, 8).unwrap());
+        });
+
+        assert_eq!(
+            memory,
+            Memory {
+                allocations: 3,
+                allocated: 16 + 1024 + 64,
+                peak: 16 + 1024,
+            }
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let memory = Memory {
+            allocations: 3,
+            allocated: 1_572_864,
+            peak: 512,
+        };
+        assert_eq!(
+            memory.to_string(),
+            "3 allocations, 1.50 MiB allocated, 512 B peak"
+        );
+    }
+}
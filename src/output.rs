@@ -0,0 +1,360 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answer::Answer;
+use crate::answers::Verdict;
+use crate::bench::Stats;
+use crate::memory::Memory;
+use crate::runner::{DayStatus, Outcome};
+use crate::solution::Day;
+use crate::timings::Comparison;
+use serde_json::json;
+use std::fmt;
+use std::str::FromStr;
+
+/// The output format of a run, selected with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+/// A single result row of a run, as emitted by the machine-readable formats.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub variant: Option<&'static str>,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub elapsed_ns: u128,
+    /// The time spent parsing the input, which is the same for both parts of a day.
+    pub parse_ns: u128,
+    pub status: Status,
+    pub message: Option<String>,
+    /// Only set in `--bench` mode, in which case `elapsed_ns` is the median.
+    pub stats: Option<Stats>,
+    /// Only set when built with the `count-allocations` feature.
+    pub memory: Option<Memory>,
+    /// Only set with `--compare` for parts that have a stored baseline.
+    pub comparison: Option<Comparison>,
+    pub verdict: Option<Verdict>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Panicked,
+    TimedOut,
+    Skipped,
+    MissingInput,
+    InvalidParams,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+            Status::Skipped => "skipped",
+            Status::MissingInput => "missing_input",
+            Status::InvalidParams => "invalid_params",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// Flattens the status of a day into one record per part, or only for `part` if one was selected.
+pub fn records(day: &Day, status: &DayStatus, part: Option<u8>) -> Vec<Record> {
+    match status {
+        DayStatus::Ran { parse, parts } => parts
+            .iter()
+            .map(|result| {
+                let (answer, status, message) = match &result.outcome {
+                    Outcome::Solved(answer) => (
+                        Some(answer.clone()),
+                        Status::Solved,
+                        answer
+                            .unread_letters()
+                            .map(|reason| format!("the letters could not be read: {}", reason)),
+                    ),
+                    Outcome::Unsolved => (None, Status::Unsolved, None),
+                    Outcome::Panicked(message) => (None, Status::Panicked, Some(message.clone())),
+                    Outcome::TimedOut(timeout) => (
+                        None,
+                        Status::TimedOut,
+                        Some(format!("timed out after {:.2?}", timeout)),
+                    ),
+                    Outcome::Skipped(reason) => (None, Status::Skipped, Some(reason.clone())),
+                };
+                Record {
+                    day: day.day,
+                    variant: day.variant,
+                    part: result.part,
+                    answer,
+                    elapsed_ns: result.elapsed.as_nanos(),
+                    parse_ns: parse.elapsed.as_nanos(),
+                    status,
+                    message,
+                    stats: result.stats,
+                    memory: result.memory,
+                    comparison: result.comparison,
+                    verdict: result.verdict.clone(),
+                }
+            })
+            .collect(),
+        DayStatus::MissingInput(reason) => not_run(
+            day,
+            part,
+            Status::MissingInput,
+            format!("missing input: {}", reason),
+        ),
+        DayStatus::InvalidParams(reason) => not_run(
+            day,
+            part,
+            Status::InvalidParams,
+            format!("invalid parameters: {}", reason),
+        ),
+    }
+}
+
+/// The records of a day that could not run.
+fn not_run(day: &Day, part: Option<u8>, status: Status, message: String) -> Vec<Record> {
+    (1..=2)
+        .filter(|&p| part.is_none_or(|selected| selected == p))
+        .map(|part| Record {
+            day: day.day,
+            variant: day.variant,
+            part,
+            answer: None,
+            elapsed_ns: 0,
+            parse_ns: 0,
+            status,
+            message: Some(message.clone()),
+            stats: None,
+            memory: None,
+            comparison: None,
+            verdict: None,
+        })
+        .collect()
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let records: Vec<_> = records
+        .iter()
+        .map(|record| {
+            let mut value = json!({
+                "day": record.day,
+                "variant": record.variant,
+                "part": record.part,
+                "answer": record.answer,
+                "elapsed_ns": record.elapsed_ns as u64,
+                "parse_ns": record.parse_ns as u64,
+                "status": record.status.to_string(),
+                "message": record.message,
+            });
+            if let Some(stats) = &record.stats {
+                value["bench"] = json!({
+                    "samples": stats.samples,
+                    "min_ns": stats.min.as_nanos() as u64,
+                    "median_ns": stats.median.as_nanos() as u64,
+                    "mean_ns": stats.mean.as_nanos() as u64,
+                    "stddev_ns": stats.stddev.as_nanos() as u64,
+                });
+            }
+            if let Some(memory) = &record.memory {
+                value["memory"] = json!({
+                    "allocations": memory.allocations,
+                    "allocated_bytes": memory.allocated,
+                    "peak_bytes": memory.peak,
+                });
+            }
+            if let Some(verdict) = &record.verdict {
+                value["verdict"] = json!(verdict.to_string());
+                if let Verdict::Wrong(expected) = verdict {
+                    value["expected"] = json!(expected);
+                }
+            }
+            if let Some(comparison) = &record.comparison {
+                value["baseline"] = json!({
+                    "elapsed_ns": comparison.baseline.as_nanos() as u64,
+                    "change": comparison.change,
+                    "regressed": comparison.regressed,
+                });
+            }
+            value
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&records).unwrap()
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from(
+        "day,variant,part,answer,elapsed_ns,parse_ns,status,message,samples,min_ns,median_ns,mean_ns,stddev_ns,allocations,allocated_bytes,peak_bytes,baseline_ns,regressed,verdict,expected\n",
+    );
+    for record in records {
+        let stat = |f: fn(&Stats) -> String| record.stats.as_ref().map(f).unwrap_or_default();
+        let memory = |f: fn(&Memory) -> String| record.memory.as_ref().map(f).unwrap_or_default();
+        let row = [
+            record.day.to_string(),
+            record.variant.unwrap_or_default().to_string(),
+            record.part.to_string(),
+            record
+                .answer
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+            record.elapsed_ns.to_string(),
+            record.parse_ns.to_string(),
+            record.status.to_string(),
+            record.message.clone().unwrap_or_default(),
+            stat(|s| s.samples.to_string()),
+            stat(|s| s.min.as_nanos().to_string()),
+            stat(|s| s.median.as_nanos().to_string()),
+            stat(|s| s.mean.as_nanos().to_string()),
+            stat(|s| s.stddev.as_nanos().to_string()),
+            memory(|m| m.allocations.to_string()),
+            memory(|m| m.allocated.to_string()),
+            memory(|m| m.peak.to_string()),
+            record
+                .comparison
+                .map(|c| c.baseline.as_nanos().to_string())
+                .unwrap_or_default(),
+            record
+                .comparison
+                .map(|c| c.regressed.to_string())
+                .unwrap_or_default(),
+            record
+                .verdict
+                .as_ref()
+                .map(Verdict::to_string)
+                .unwrap_or_default(),
+            match &record.verdict {
+                Some(Verdict::Wrong(expected)) => expected.clone(),
+                _ => String::new(),
+            },
+        ];
+        let row: Vec<String> = row.iter().map(|field| escape_csv(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Prints records in a machine-readable format. Text output is printed while running instead.
+pub fn print_records(format: Format, records: &[Record]) {
+    match format {
+        Format::Json => println!("{}", to_json(records)),
+        Format::Csv => print!("{}", to_csv(records)),
+        Format::Text => {}
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Option<&str>, status: Status) -> Record {
+        Record {
+            day: 5,
+            variant: None,
+            part: 1,
+            answer: answer.map(Answer::from),
+            elapsed_ns: 1_500,
+            parse_ns: 500,
+            status,
+            message: None,
+            stats: None,
+            memory: None,
+            comparison: None,
+            verdict: None,
+        }
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let records = [
+            record(Some("CMZ"), Status::Solved),
+            record(Some("a,\"b\""), Status::Solved),
+            record(None, Status::Unsolved),
+        ];
+        assert_eq!(
+            to_csv(&records),
+            "day,variant,part,answer,elapsed_ns,parse_ns,status,message,samples,min_ns,median_ns,mean_ns,stddev_ns,allocations,allocated_bytes,peak_bytes,baseline_ns,regressed,verdict,expected\n\
+             5,,1,CMZ,1500,500,solved,,,,,,,,,,,,,\n\
+             5,,1,\"a,\"\"b\"\"\",1500,500,solved,,,,,,,,,,,,,\n\
+             5,,1,,1500,500,unsolved,,,,,,,,,,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_records_not_run() {
+        let day = crate::days::get(2022, 1).unwrap();
+        let status = DayStatus::MissingInput("no file".to_string());
+        assert_eq!(records(day, &status, None).len(), 2);
+
+        let records = records(day, &status, Some(2));
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            (records[0].part, records[0].status),
+            (2, Status::MissingInput)
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&to_json(&[record(Some("CMZ"), Status::Solved)])).unwrap();
+        assert_eq!(
+            json,
+            json!([{
+                "day": 5,
+                "variant": null,
+                "part": 1,
+                "answer": "CMZ",
+                "elapsed_ns": 1500,
+                "parse_ns": 500,
+                "status": "solved",
+                "message": null,
+            }])
+        );
+    }
+}
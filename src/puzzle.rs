@@ -0,0 +1,377 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::client::{self, decode_entities, Client, AOC_URL};
+use crate::input::crate_path;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::fs;
+
+pub fn puzzle_path(year: i16, day: u8) -> String {
+    format!("src/puzzles/{}/{:02}.md", year, day)
+}
+
+/// Downloads the puzzle description for a day and caches it as Markdown in `src/puzzles/`.
+pub fn fetch(year: i16, day: u8) -> Result<String, String> {
+    let session = client::session()?;
+    let html = Client::new(&session).puzzle(year, day)?;
+    let markdown = to_markdown(&html).ok_or("could not find a puzzle description in the page.")?;
+
+    let path = puzzle_path(year, day);
+    let resolved = crate_path(&path);
+    if let Some(parent) = resolved.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("could not create \"{}\": {}", parent.display(), e))?;
+    }
+    fs::write(&resolved, &markdown).map_err(|e| format!("could not write \"{}\": {}", path, e))?;
+
+    Ok(markdown)
+}
+
+/// Extracts the fenced code blocks of a description. The first one is usually the example input.
+pub fn examples(markdown: &str) -> Vec<String> {
+    let mut examples = vec![];
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            match block.take() {
+                Some(example) => examples.push(example),
+                None => block = Some(String::new()),
+            }
+        } else if let Some(example) = block.as_mut() {
+            example.push_str(line);
+            example.push('\n');
+        }
+    }
+
+    examples
+}
+
+/// Guesses the example answer of a part: the last highlighted code value in its description,
+/// `<code><em>x</em></code>` on the page. Plain emphasis is ignored, it is used for all kinds of text.
+pub fn expected_answer(markdown: &str, part: u8) -> Option<String> {
+    let (part_one, part_two) = match markdown.split_once("## --- Part Two ---") {
+        Some((part_one, part_two)) => (part_one, Some(part_two)),
+        None => (markdown, None),
+    };
+    let section = match part {
+        1 => part_one,
+        _ => part_two?,
+    };
+
+    let mut answer = None;
+    let mut in_block = false;
+    for line in section.lines() {
+        if line.starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+        if in_block {
+            continue;
+        }
+        let mut rest = line;
+        while let Some(start) = rest.find("*`") {
+            let rest_of_value = &rest[start + 2..];
+            let end = match rest_of_value.find("`*") {
+                Some(end) => end,
+                None => break,
+            };
+            let value = &rest_of_value[..end];
+            if !value.is_empty() && !value.contains(char::is_whitespace) {
+                answer = Some(value.to_string());
+            }
+            rest = &rest_of_value[end + 2..];
+        }
+    }
+    answer
+}
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Splits HTML into tags (name and attributes) and text.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                // an unclosed tag runs to the end of the page.
+                let end = rest.find('>').unwrap_or(rest.len());
+                let tag = &rest[1..end];
+                match tag.strip_prefix('/') {
+                    Some(name) => tokens.push(Token::Close(name.trim())),
+                    None => {
+                        let tag = tag.trim_end_matches('/');
+                        let (name, attributes) = tag.split_once(' ').unwrap_or((tag, ""));
+                        tokens.push(Token::Open(name, attributes));
+                    }
+                }
+                rest = rest.get(end + 1..).unwrap_or_default();
+            }
+            Some(start) => {
+                tokens.push(Token::Text(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                rest = "";
+            }
+        }
+    }
+
+    tokens
+}
+
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = attributes[start..].find('"')? + start;
+    Some(&attributes[start..end])
+}
+
+/// Converts the `<article>` elements of a puzzle page, one per unlocked part, to Markdown.
+pub fn to_markdown(html: &str) -> Option<String> {
+    let mut markdown = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        // an unclosed article is dropped, the ones before it are kept.
+        let end = match rest[start..].find("</article>") {
+            Some(end) => end + start,
+            None => break,
+        };
+        article_to_markdown(&rest[start..end], &mut markdown);
+        rest = &rest[end..];
+    }
+
+    if markdown.is_empty() {
+        return None;
+    }
+
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    Some(markdown.trim().to_string() + "\n")
+}
+
+fn article_to_markdown(html: &str, markdown: &mut String) {
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links = vec![];
+    let tokens = tokenize(html);
+    let mut i = 0;
+
+    while i < tokens.len() {
+        // `<code><em>x</em></code>` marks a highlighted value, such as an example answer.
+        if let [Token::Open("code", _), Token::Open("em", _), Token::Text(text), Token::Close("em"), Token::Close("code"), ..] =
+            tokens[i..]
+        {
+            if !in_pre {
+                markdown.push_str(&format!("*`{}`*", decode_entities(text)));
+                i += 5;
+                continue;
+            }
+        }
+
+        let token = &tokens[i];
+        i += 1;
+        match *token {
+            Token::Open("h2", _) => markdown.push_str("\n## "),
+            Token::Close("h2") | Token::Close("p") => markdown.push_str("\n\n"),
+            Token::Open("pre", _) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            Token::Open("code", _) | Token::Close("code") if !in_pre => {
+                in_code = !in_code;
+                markdown.push('`');
+            }
+            Token::Open("em", _) | Token::Close("em") if !in_pre && !in_code => markdown.push('*'),
+            Token::Open("li", _) => markdown.push_str("- "),
+            Token::Close("li") => markdown.push('\n'),
+            Token::Close("ul") => markdown.push('\n'),
+            Token::Open("a", attributes) => {
+                let href = attribute(attributes, "href").unwrap_or_default();
+                let href = if href.starts_with('/') {
+                    format!("{}{}", AOC_URL, href)
+                } else {
+                    href.to_string()
+                };
+                links.push(href);
+                markdown.push('[');
+            }
+            Token::Close("a") => {
+                markdown.push_str(&format!("]({})", links.pop().unwrap_or_default()));
+            }
+            Token::Text(text) if in_pre => markdown.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                let text = decode_entities(&text.replace('\n', " "));
+                // whitespace between block elements.
+                if !text.trim().is_empty() || !markdown.ends_with(['\n', ' ']) {
+                    markdown.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Renders Markdown produced by [`to_markdown`] for the terminal.
+pub fn render(markdown: &str) -> String {
+    let mut rendered = String::new();
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_block = !in_block;
+        } else if in_block {
+            rendered.push_str(&format!("    {}\n", line));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            rendered.push_str(&format!("{}{}{}\n", ANSI_BOLD, heading, ANSI_RESET));
+        } else {
+            rendered.push_str(&render_inline(line));
+            rendered.push('\n');
+        }
+    }
+
+    rendered
+}
+
+fn render_inline(line: &str) -> String {
+    let mut rendered = String::new();
+    let mut bold = false;
+    let mut code = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => {
+                code = !code;
+                rendered.push_str(if code { ANSI_ITALIC } else { ANSI_RESET });
+            }
+            '*' if !code => {
+                bold = !bold;
+                rendered.push_str(if bold { ANSI_BOLD } else { ANSI_RESET });
+            }
+            // links are rendered as their text.
+            ']' if !code && chars.peek() == Some(&'(') => {
+                for c in chars.by_ref() {
+                    if c == ')' {
+                        break;
+                    }
+                }
+            }
+            '[' if !code => {}
+            c => rendered.push(c),
+        }
+    }
+
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The Elves take turns writing down the number of <em>Calories</em> contained by the various items.</p>
+<p>For example:</p>
+<pre><code>1000
+<em>2000</em>
+</code></pre>
+<ul>
+<li>The first Elf is carrying <code>6000</code> Calories.</li>
+<li>Find the Elf carrying the <em>most Calories</em>.</li>
+</ul>
+<p>See <a href="/2022/day/1/input">your input</a> &amp; more.</p>
+</article>
+<p>Your puzzle answer was <code>71124</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the <em>top three</em> Elves.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE).unwrap(),
+            "## --- Day 1: Calorie Counting ---\n\n\
+             The Elves take turns writing down the number of *Calories* contained by the various items.\n\n\
+             For example:\n\n\
+             ```\n1000\n2000\n```\n\n\
+             - The first Elf is carrying `6000` Calories.\n\
+             - Find the Elf carrying the *most Calories*.\n\n\
+             See [your input](https://adventofcode.com/2022/day/1/input) & more.\n\n\
+             ## --- Part Two ---\n\n\
+             Find the *top three* Elves.\n"
+        );
+        assert_eq!(to_markdown("<html>Please log in</html>"), None);
+        assert_eq!(
+            to_markdown("<article><p>Part one</p></article><article><p>Part two").unwrap(),
+            "Part one\n"
+        );
+        assert_eq!(to_markdown("<article><p>Part one"), None);
+    }
+
+    #[test]
+    fn test_examples_and_expected_answers() {
+        let markdown = to_markdown(
+            "<article><h2>--- Day 5 ---</h2><pre><code>    [D]\n[N] [C]\n</code></pre>\
+             <pre><code>move 1 from 2 to 1</code></pre>\
+             <p>The crates end up as <code><em>CMZ</em></code>, in <em>this order</em>, <em>once</em>.</p></article>\
+             <article><h2 id=\"part2\">--- Part Two ---</h2><p>Now it is <em><code>MCD</code></em>.</p></article>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            examples(&markdown),
+            vec!["    [D]\n[N] [C]\n", "move 1 from 2 to 1\n"]
+        );
+        assert_eq!(expected_answer(&markdown, 1), Some("CMZ".to_string()));
+        assert_eq!(expected_answer(&markdown, 2), Some("MCD".to_string()));
+
+        let part_one = markdown.split("## --- Part Two").next().unwrap();
+        assert_eq!(expected_answer(part_one, 2), None);
+        assert_eq!(expected_answer("The answer is *42*.", 1), None);
+    }
+
+    #[test]
+    fn test_malformed_html() {
+        for html in [
+            "<",
+            "<article>ü<",
+            "<article><em>ü",
+            "<article>ü</article><p",
+        ] {
+            let _ = to_markdown(html);
+        }
+        assert_eq!(
+            to_markdown("<article><p>Café <em>x</em></p></article><p").unwrap(),
+            "Café *x*\n"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("## Title\n\nSee *this* `1 * 2` [link](https://x.y).\n```\n1000\n```\n"),
+            format!(
+                "{b}Title{r}\n\nSee {b}this{r} {i}1 * 2{r} link.\n    1000\n",
+                b = ANSI_BOLD,
+                i = ANSI_ITALIC,
+                r = ANSI_RESET
+            )
+        );
+    }
+}
@@ -0,0 +1,258 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::input::crate_path;
+use crate::timings::Timings;
+use std::fs;
+use std::time::Duration;
+
+pub const README_PATH: &str = "README.md";
+
+/// Surrounds the stars table in `README.md`, shared with the `readme-stars` workflow.
+pub const STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Surrounds the benchmark table in `README.md`.
+pub const BENCHMARK_MARKER: &str = "<!--- benchmarking table --->";
+
+/// The indices of the two lines that consist of `marker`.
+fn section_bounds(lines: &[&str], marker: &str) -> Result<(usize, usize), String> {
+    let markers: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].trim() == marker)
+        .collect();
+    match markers[..] {
+        [start, end] => Ok((start, end)),
+        _ => Err(format!(
+            "expected two lines with \"{}\" around the section, found {}",
+            marker,
+            markers.len()
+        )),
+    }
+}
+
+/// Everything between the two lines that consist of `marker`.
+pub fn section(contents: &str, marker: &str) -> Result<String, String> {
+    let lines: Vec<&str> = contents.split_inclusive('\n').collect();
+    let (start, end) = section_bounds(&lines, marker)?;
+    Ok(lines[start + 1..end].concat())
+}
+
+/// Replaces everything between the two lines that consist of `marker`, keeping the markers
+/// and every other line as they are.
+pub fn replace_section(contents: &str, marker: &str, section: &str) -> Result<String, String> {
+    let lines: Vec<&str> = contents.split_inclusive('\n').collect();
+    let (start, end) = section_bounds(&lines, marker)?;
+
+    let newline = if lines[start].ends_with("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut updated: String = lines[..=start].concat();
+    for line in section.lines() {
+        updated.push_str(line);
+        updated.push_str(newline);
+    }
+    updated.push_str(&lines[end..].concat());
+    Ok(updated)
+}
+
+/// Rewrites the section of a file between two `marker` lines, with `path` relative to the crate
+/// root. Returns `false` if nothing changed.
+pub fn update_file(path: &str, marker: &str, section: &str) -> Result<bool, String> {
+    let resolved = crate_path(path);
+    let contents =
+        fs::read_to_string(&resolved).map_err(|e| format!("could not read \"{}\": {}", path, e))?;
+    let updated = replace_section(&contents, marker, section)
+        .map_err(|e| format!("{} in \"{}\"", e, path))?;
+    if updated == contents {
+        return Ok(false);
+    }
+    fs::write(&resolved, updated).map_err(|e| format!("could not write \"{}\": {}", path, e))?;
+    Ok(true)
+}
+
+/// The parts with a star in `table`, if it is the stars table of `year`.
+fn stars_in_table(year: i16, table: &str) -> Vec<(u8, u8)> {
+    let heading = format!("## {} Results", year);
+    if !table.lines().any(|line| line == heading) {
+        return vec![];
+    }
+    table
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+            let day = cells.get(1)?.strip_prefix("[Day ")?.split(']').next()?;
+            Some((day.parse::<u8>().ok()?, cells))
+        })
+        .flat_map(|(day, cells)| {
+            [1, 2]
+                .into_iter()
+                .filter(move |&part| cells.get(part as usize + 1) == Some(&"⭐"))
+                .map(move |part| (day, part))
+        })
+        .collect()
+}
+
+/// A markdown table of the solved parts of every day with an accepted answer, in the format of
+/// the `readme-stars` workflow. Stars of `year` in the `current` table are kept, e.g. of parts
+/// that were solved on the website but never recorded locally.
+pub fn stars_table(year: i16, answers: &Answers, current: &str) -> String {
+    let stars = stars_in_table(year, current);
+    let mut table = format!(
+        "## {} Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year
+    );
+    let star = |solved: bool| if solved { "⭐" } else { " " };
+    for day in 1..=25 {
+        let solved =
+            [1, 2].map(|part| answers.is_solved(day, part) || stars.contains(&(day, part)));
+        if !solved.contains(&true) {
+            continue;
+        }
+        table.push_str(&format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |\n",
+            day,
+            year,
+            day,
+            star(solved[0]),
+            star(solved[1])
+        ));
+    }
+    table
+}
+
+/// A markdown table of the stored timings of every day's main implementation, and their total.
+pub fn benchmark_table(year: i16, timings: &Timings) -> String {
+    let days: Vec<(u8, [Option<Duration>; 2])> = timings
+        .days
+        .iter()
+        .filter_map(|(name, parts)| {
+            // variants such as `05_sub_optimal` are left out.
+            let day = name.parse::<u8>().ok()?;
+            Some((
+                day,
+                [1, 2].map(|part| parts.get(&part).map(|t| t.elapsed())),
+            ))
+        })
+        .collect();
+
+    if days.is_empty() {
+        return format!(
+            "## {} Benchmarks\n\nNo timings are stored yet, run `cargo run --release --bin aoc -- readme --run` to record them.",
+            year
+        );
+    }
+
+    let cell = |elapsed: Option<Duration>| match elapsed {
+        Some(elapsed) => format!("`{:.2?}`", elapsed),
+        None => "-".to_string(),
+    };
+    let mut table = format!(
+        "## {} Benchmarks\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year
+    );
+    for (day, parts) in &days {
+        table.push_str(&format!(
+            "| [Day {}](./src/y{}/day{:02}.rs) | {} | {} |\n",
+            day,
+            year,
+            day,
+            cell(parts[0]),
+            cell(parts[1])
+        ));
+    }
+    let total: Duration = days
+        .iter()
+        .flat_map(|(_, parts)| parts.iter().flatten())
+        .sum();
+    table.push_str(&format!(
+        "\n**Total: {:.2}ms**",
+        total.as_secs_f64() * 1000_f64
+    ));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timings::Timing;
+
+    const README: &str = "# Title\n<!--- table --->\nold\n<!--- table --->\nrest\n";
+
+    #[test]
+    fn test_replace_section() {
+        let updated = replace_section(README, "<!--- table --->", "new\nlines").unwrap();
+        assert_eq!(
+            updated,
+            "# Title\n<!--- table --->\nnew\nlines\n<!--- table --->\nrest\n"
+        );
+        assert_eq!(
+            replace_section(&updated, "<!--- table --->", "new\nlines").unwrap(),
+            updated
+        );
+        assert!(replace_section("# Title\n", "<!--- table --->", "new").is_err());
+        assert_eq!(
+            replace_section(
+                "<!--- table --->\r\n<!--- table --->",
+                "<!--- table --->",
+                "new"
+            )
+            .unwrap(),
+            "<!--- table --->\r\nnew\r\n<!--- table --->"
+        );
+    }
+
+    #[test]
+    fn test_stars_table() {
+        let mut answers = Answers::default();
+        answers.insert(1, "abc", 1, "24000");
+        answers.insert(1, "abc", 2, "45000");
+        answers.insert(21, "abc", 1, "152");
+        answers.insert(21, "def", 1, "153");
+
+        let table = stars_table(2022, &answers, "");
+        assert_eq!(
+            table,
+            "## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n\
+             | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n\
+             | [Day 21](https://adventofcode.com/2022/day/21) | ⭐ |   |\n"
+        );
+
+        // stars already in the table are kept, but only for the same year.
+        assert_eq!(stars_table(2022, &Answers::default(), &table), table);
+        assert_eq!(
+            stars_table(2021, &Answers::default(), &table),
+            "## 2021 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n"
+        );
+        let mut answers = Answers::default();
+        answers.insert(21, "abc", 2, "301");
+        assert!(stars_table(2022, &answers, &table)
+            .contains("| [Day 21](https://adventofcode.com/2022/day/21) | ⭐ | ⭐ |\n"));
+    }
+
+    #[test]
+    fn test_section() {
+        assert_eq!(section(README, "<!--- table --->").unwrap(), "old\n");
+        assert!(section("# Title\n", "<!--- table --->").is_err());
+    }
+
+    #[test]
+    fn test_benchmark_table() {
+        let mut timings = Timings::default();
+        let timing = |ns| Timing {
+            elapsed_ns: ns,
+            bench: true,
+        };
+        timings.insert("01", 1, timing(1_500));
+        timings.insert("01", 2, timing(2_500_000));
+        timings.insert("05_sub_optimal", 1, timing(1_000_000_000));
+
+        assert_eq!(
+            benchmark_table(2022, &timings),
+            "## 2022 Benchmarks\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n\
+             | [Day 1](./src/y2022/day01.rs) | `1.50µs` | `2.50ms` |\n\n**Total: 2.50ms**"
+        );
+    }
+}
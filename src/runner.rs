@@ -0,0 +1,946 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answer::Answer;
+use crate::answers::{self, answers_path, Answers, Verdict};
+use crate::args::RunArgs;
+use crate::bench::{self, BenchConfig, Stats};
+use crate::examples::{self, Example};
+use crate::input::{self, Normalize};
+use crate::memory::{self, Memory};
+use crate::output::{self, Format};
+use crate::solution::{self, Day, Params};
+use crate::store::Store;
+use crate::timings::{timings_path, Comparison, Timing, Timings};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The outcome of running a single part of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    Unsolved,
+    Panicked(String),
+    /// The part did not finish within the timeout.
+    TimedOut(Duration),
+    /// The part was not started because an earlier step timed out, with the reason.
+    Skipped(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub outcome: Outcome,
+    /// The time of a single run, or the median time in `--bench` mode.
+    pub elapsed: Duration,
+    pub stats: Option<Stats>,
+    /// Set when built with the `count-allocations` feature.
+    pub memory: Option<Memory>,
+    /// Set when running with `--compare` and a baseline is stored for this part.
+    pub comparison: Option<Comparison>,
+    /// Set for solved parts, by checking the answer against the known answers.
+    pub verdict: Option<Verdict>,
+}
+
+/// The time spent parsing the input, which is shared by both parts.
+#[derive(Debug, Clone)]
+pub struct ParseResult {
+    /// The time of a single run, or the median time in `--bench` mode.
+    pub elapsed: Duration,
+    pub stats: Option<Stats>,
+    /// Set when built with the `count-allocations` feature.
+    pub memory: Option<Memory>,
+}
+
+/// The status of a day in an all-days run.
+#[derive(Debug, Clone)]
+pub enum DayStatus {
+    Ran {
+        parse: ParseResult,
+        parts: Vec<PartResult>,
+    },
+    /// Holds why the input could not be read.
+    MissingInput(String),
+    /// Holds why the parameters of the day are invalid, e.g. an unknown `--param`.
+    InvalidParams(String),
+}
+
+impl DayStatus {
+    /// The time spent parsing and solving.
+    pub fn elapsed(&self) -> Duration {
+        match self {
+            DayStatus::Ran { parse, parts } => {
+                parse.elapsed
+                    + parts
+                        .iter()
+                        .filter(|part| matches!(part.outcome, Outcome::Solved(_)))
+                        .map(|part| part.elapsed)
+                        .sum::<Duration>()
+            }
+            DayStatus::MissingInput(_) | DayStatus::InvalidParams(_) => Duration::ZERO,
+        }
+    }
+
+    /// Whether the day could not run, or one of its parts panicked or timed out.
+    pub fn failed(&self) -> bool {
+        match self {
+            DayStatus::Ran { parts, .. } => parts
+                .iter()
+                .any(|part| matches!(part.outcome, Outcome::Panicked(_) | Outcome::TimedOut(_))),
+            DayStatus::MissingInput(_) | DayStatus::InvalidParams(_) => true,
+        }
+    }
+}
+
+/// Runs a single part, catching any panic raised by the solution.
+/// Solved parts are benchmarked afterwards if a bench config is given.
+pub fn run_part(
+    part: u8,
+    func: impl Fn() -> Option<Answer>,
+    bench_config: Option<&BenchConfig>,
+) -> PartResult {
+    let mut result = time_part(part, &func);
+    bench_part(&mut result, &func, bench_config);
+    result
+}
+
+fn time_part(part: u8, func: &dyn Fn() -> Option<Answer>) -> PartResult {
+    let timer = Instant::now();
+    let (result, memory) = memory::measure(|| panic::catch_unwind(AssertUnwindSafe(func)));
+    let elapsed = timer.elapsed();
+
+    let outcome = match result {
+        Ok(Some(answer)) => Outcome::Solved(answer),
+        Ok(None) => Outcome::Unsolved,
+        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+    };
+
+    PartResult {
+        part,
+        outcome,
+        elapsed,
+        stats: None,
+        memory,
+        comparison: None,
+        verdict: None,
+    }
+}
+
+fn bench_part(
+    result: &mut PartResult,
+    func: &dyn Fn() -> Option<Answer>,
+    bench_config: Option<&BenchConfig>,
+) {
+    if let (Outcome::Solved(_), Some(config)) = (&result.outcome, bench_config) {
+        let stats = bench::bench(config, func);
+        result.elapsed = stats.median;
+        result.stats = Some(stats);
+    }
+}
+
+fn part_result(part: u8, outcome: Outcome) -> PartResult {
+    PartResult {
+        part,
+        outcome,
+        elapsed: Duration::ZERO,
+        stats: None,
+        memory: None,
+        comparison: None,
+        verdict: None,
+    }
+}
+
+/// Reported by a day running on its own thread, so the runner can tell which step timed out.
+enum Progress {
+    /// The single timed run of the current step finished, it may still be benchmarked.
+    Timed,
+    Parsed(ParseResult),
+    Part(PartResult),
+}
+
+/// How the steps of a day are run, see [`run_day`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Benchmarks parsing and every solved part.
+    pub bench: Option<BenchConfig>,
+    /// Runs only this part.
+    pub part: Option<u8>,
+    /// Gives up on a step whose single timed run takes longer. Benchmarking is not limited.
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    pub fn from_args(args: &RunArgs) -> Self {
+        RunOptions {
+            bench: args.bench,
+            part: args.part,
+            timeout: args.timeout,
+        }
+    }
+
+    pub fn selects(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+
+    fn selected_parts(&self) -> impl Iterator<Item = u8> + '_ {
+        (1..=2).filter(|&part| self.selects(part))
+    }
+}
+
+/// Times the steps of a day as it is run, see [`solution::Visitor`].
+struct Timer {
+    options: RunOptions,
+    parse: ParseResult,
+    /// Set if parsing panicked, in which case neither part is run.
+    parse_panic: Option<String>,
+    parts: Vec<PartResult>,
+    progress: Option<Sender<Progress>>,
+}
+
+impl Timer {
+    fn report(&self, progress: Progress) {
+        if let Some(sender) = &self.progress {
+            // the runner stops listening once a step times out.
+            let _ = sender.send(progress);
+        }
+    }
+}
+
+impl solution::Visitor for Timer {
+    fn parse(&mut self, parse: &dyn Fn()) {
+        let timer = Instant::now();
+        let (result, memory) = memory::measure(|| panic::catch_unwind(AssertUnwindSafe(parse)));
+        self.parse.elapsed = timer.elapsed();
+        self.parse.memory = memory;
+        self.report(Progress::Timed);
+
+        match result {
+            Ok(()) => {
+                if let Some(config) = &self.options.bench {
+                    let stats = bench::bench(config, parse);
+                    self.parse.elapsed = stats.median;
+                    self.parse.stats = Some(stats);
+                }
+            }
+            Err(payload) => self.parse_panic = Some(panic_message(payload.as_ref())),
+        }
+        self.report(Progress::Parsed(self.parse.clone()));
+    }
+
+    fn part(&mut self, part: u8, solve: &dyn Fn() -> Option<Answer>) {
+        if !self.options.selects(part) {
+            return;
+        }
+        let mut result = time_part(part, solve);
+        self.report(Progress::Timed);
+        bench_part(&mut result, solve, self.options.bench.as_ref());
+        self.report(Progress::Part(result.clone()));
+        self.parts.push(result);
+    }
+}
+
+/// Parses the input once and runs the selected parts on it. If parsing panics, every selected part reports the panic.
+pub fn run_day(
+    day: &Day,
+    input: &str,
+    params: &Params,
+    options: &RunOptions,
+) -> (ParseResult, Vec<PartResult>) {
+    match options.timeout {
+        Some(timeout) => run_day_with_timeout(day, input, params, options, timeout),
+        None => run_day_reporting(day, input, params, options, None),
+    }
+}
+
+fn run_day_reporting(
+    day: &Day,
+    input: &str,
+    params: &Params,
+    options: &RunOptions,
+    progress: Option<Sender<Progress>>,
+) -> (ParseResult, Vec<PartResult>) {
+    let mut timer = Timer {
+        options: *options,
+        parse: empty_parse(),
+        parse_panic: None,
+        parts: vec![],
+        progress,
+    };
+    day.run(input, params, &mut timer);
+
+    if let Some(message) = timer.parse_panic {
+        timer.parts = options
+            .selected_parts()
+            .map(|part| {
+                part_result(
+                    part,
+                    Outcome::Panicked(format!("while parsing: {}", message)),
+                )
+            })
+            .collect();
+    }
+    (timer.parse, timer.parts)
+}
+
+/// Set once a step timed out. Its thread keeps running, so later timings are not reliable.
+static TIMED_OUT: AtomicBool = AtomicBool::new(false);
+
+/// Whether a timed-out step is still running in the background.
+pub fn timed_out_in_background() -> bool {
+    TIMED_OUT.load(Ordering::Relaxed)
+}
+
+/// Runs the day on its own thread and reports steps that do not finish within `timeout`.
+/// The parts after a step that times out are skipped.
+///
+/// A step that times out cannot be stopped, so its thread is left running in the background
+/// until the process exits. Memory is no longer measured from then on, see [`memory::disable`].
+fn run_day_with_timeout(
+    day: &Day,
+    input: &str,
+    params: &Params,
+    options: &RunOptions,
+    timeout: Duration,
+) -> (ParseResult, Vec<PartResult>) {
+    let (sender, receiver) = mpsc::channel();
+    let (day, input, params, options) = (*day, input.to_string(), params.clone(), *options);
+    let handle =
+        thread::spawn(move || run_day_reporting(&day, &input, &params, &options, Some(sender)));
+
+    let mut parse = None;
+    let mut parts: Vec<PartResult> = vec![];
+    let mut timing = true;
+    loop {
+        let progress = if timing {
+            receiver.recv_timeout(timeout)
+        } else {
+            receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        };
+        match progress {
+            Ok(Progress::Timed) => timing = false,
+            Ok(Progress::Parsed(result)) => {
+                parse = Some(result);
+                timing = true;
+            }
+            Ok(Progress::Part(result)) => {
+                parts.push(result);
+                timing = true;
+            }
+            Err(RecvTimeoutError::Disconnected) => match handle.join() {
+                Ok(results) => return results,
+                Err(payload) => {
+                    let message = panic_message(payload.as_ref());
+                    let parts = options
+                        .selected_parts()
+                        .map(|part| part_result(part, Outcome::Panicked(message.clone())))
+                        .collect();
+                    return (parse.unwrap_or_else(empty_parse), parts);
+                }
+            },
+            Err(RecvTimeoutError::Timeout) => break,
+        }
+    }
+
+    TIMED_OUT.store(true, Ordering::Relaxed);
+    memory::disable();
+
+    let (parse, mut skipped) = match parse {
+        Some(parse) => (parse, None),
+        None => (
+            ParseResult {
+                elapsed: timeout,
+                ..empty_parse()
+            },
+            Some(format!("parsing timed out after {:.2?}", timeout)),
+        ),
+    };
+    let remaining: Vec<u8> = options
+        .selected_parts()
+        .filter(|&part| parts.iter().all(|result| result.part != part))
+        .collect();
+    for part in remaining {
+        let result = match &skipped {
+            Some(reason) => part_result(part, Outcome::Skipped(reason.clone())),
+            None => {
+                skipped = Some(format!("part {} timed out", part));
+                PartResult {
+                    elapsed: timeout,
+                    ..part_result(part, Outcome::TimedOut(timeout))
+                }
+            }
+        };
+        parts.push(result);
+    }
+    (parse, parts)
+}
+
+fn empty_parse() -> ParseResult {
+    ParseResult {
+        elapsed: Duration::ZERO,
+        stats: None,
+        memory: None,
+    }
+}
+
+/// A run over one or more days that compares against and records timings as requested by its args.
+pub struct Runner<'a> {
+    args: &'a RunArgs,
+    baseline: Option<Timings>,
+    /// The timings of this run, saved with `--save-timings` on top of the stored ones.
+    recorded: Timings,
+    regressions: Vec<(String, u8, Comparison)>,
+    /// Parts not compared because their baseline was recorded with or without `--bench` unlike this run.
+    other_mode: Vec<(String, u8)>,
+    answers: Answers,
+    wrong_answers: Vec<(String, u8, String)>,
+    /// Accepted answers that `--overwrite` replaced, with their previous value.
+    replaced_answers: Vec<(String, u8, String)>,
+}
+
+impl<'a> Runner<'a> {
+    pub fn new(args: &'a RunArgs) -> Result<Self, String> {
+        let baseline = match args.compare {
+            Some(_) => Some(Timings::load(timings_path(args.year))?),
+            None => None,
+        };
+        let recorded = if args.save_timings {
+            Timings::load(timings_path(args.year))?
+        } else {
+            Timings::default()
+        };
+
+        Ok(Runner {
+            args,
+            baseline,
+            recorded,
+            regressions: vec![],
+            other_mode: vec![],
+            answers: Answers::load(answers_path(args.year))?,
+            wrong_answers: vec![],
+            replaced_answers: vec![],
+        })
+    }
+
+    /// Runs a day against its input, or its example with `--example`.
+    /// Answers are checked against the store when run on the real input with default params, and
+    /// against the declared answers of the example unless `--param` overrides its params.
+    pub fn run(&mut self, day: &Day) -> DayStatus {
+        let year = self.args.year;
+        let example = if self.args.example {
+            match examples::default_example(year, day.day) {
+                Ok(example) => Some(example),
+                Err(e) => return DayStatus::MissingInput(e),
+            }
+        } else {
+            None
+        };
+        let source = match (&example, &self.args.input) {
+            (Some(example), _) => example.source.clone(),
+            (None, Some(source)) => source.clone(),
+            (None, None) => crate::file_source("inputs", year, day.day),
+        };
+        let raw = match source.read() {
+            Ok(raw) => raw,
+            Err(e) => return DayStatus::MissingInput(e.to_string()),
+        };
+
+        // parameters passed on the command line win over those of the example.
+        let mut overrides = example.as_ref().map(Example::overrides).unwrap_or_default();
+        overrides.extend(self.args.params.iter().cloned());
+        let params = match Params::new(day.params, &overrides) {
+            Ok(params) => params,
+            Err(e) => return DayStatus::InvalidParams(e),
+        };
+
+        // a step left running by an earlier timeout competes for the CPU with this day.
+        let contended = timed_out_in_background();
+        let input = day.prepare(&raw);
+        let (parse, mut results) = without_panic_output(|| {
+            run_day(day, &input, &params, &RunOptions::from_args(self.args))
+        });
+        let name = day.name();
+        // keyed independently of the day's policy, so variants share answers.
+        let input_hash = answers::input_hash(&input::normalize(&raw, Normalize::LineEndings));
+        // answers are only known for the params of the example or the real input.
+        let overridden = match &example {
+            Some(_) => !self.args.params.is_empty(),
+            None => params.is_overridden(),
+        };
+
+        for result in results.iter_mut() {
+            let (answer, unread) = match &result.outcome {
+                Outcome::Solved(answer) => (answer.to_string(), answer.unread_letters().is_some()),
+                _ => continue,
+            };
+            if overridden {
+                continue;
+            }
+
+            if let Some(example) = &example {
+                if let Some(expected) = example.expected.part(result.part) {
+                    result.verdict =
+                        Some(self.check_example(&name, result.part, &answer, expected));
+                }
+                continue;
+            }
+            let verdict = if unread {
+                // a picture that could not be read is neither accepted nor checked.
+                Verdict::Unknown
+            } else if self.args.accept {
+                self.answers.accept(
+                    day.day,
+                    &input_hash,
+                    result.part,
+                    &answer,
+                    self.args.overwrite,
+                )
+            } else {
+                self.answers
+                    .verify(day.day, &input_hash, result.part, &answer)
+            };
+            if let Verdict::Wrong(expected) = &verdict {
+                let wrong = (name.clone(), result.part, expected.clone());
+                if self.args.overwrite {
+                    self.replaced_answers.push(wrong);
+                } else {
+                    self.wrong_answers.push(wrong);
+                }
+            }
+            result.verdict = Some(verdict);
+
+            if contended {
+                continue;
+            }
+            if let (Some(baseline), Some(threshold)) = (&self.baseline, self.args.compare) {
+                let bench = result.stats.is_some();
+                // a `--bench` median is only compared with a `--bench` median, and vice versa.
+                let timing = baseline.get(&name, result.part);
+                if timing.is_some_and(|timing| timing.bench != bench) {
+                    self.other_mode.push((name.clone(), result.part));
+                }
+                result.comparison = timing
+                    .filter(|timing| timing.bench == bench)
+                    .map(|timing| Comparison::new(result.elapsed, timing.elapsed(), threshold));
+                if let Some(comparison) = result.comparison.filter(|c| c.regressed) {
+                    self.regressions
+                        .push((name.clone(), result.part, comparison));
+                }
+            }
+
+            let timing = Timing {
+                elapsed_ns: result.elapsed.as_nanos() as u64,
+                bench: result.stats.is_some(),
+            };
+            self.recorded.insert(&name, result.part, timing);
+        }
+
+        DayStatus::Ran {
+            parse,
+            parts: results,
+        }
+    }
+
+    fn check_example(&mut self, name: &str, part: u8, answer: &str, expected: &Answer) -> Verdict {
+        let expected = expected.to_string();
+        if answer == expected {
+            Verdict::Correct
+        } else {
+            self.wrong_answers
+                .push((name.to_string(), part, expected.clone()));
+            Verdict::Wrong(expected)
+        }
+    }
+
+    /// Saves recorded timings and answers, and reports regressions and wrong answers.
+    /// Returns `false` if any part regressed or returned a wrong answer.
+    pub fn finish(self) -> Result<bool, String> {
+        if timed_out_in_background() && (self.baseline.is_some() || self.args.save_timings) {
+            eprintln!(
+                "A timed-out step kept running, the days after it were not compared or saved."
+            );
+        }
+
+        if self.args.accept {
+            let path = answers_path(self.args.year);
+            self.answers
+                .save(&path)
+                .map_err(|e| format!("could not write \"{}\": {}", path, e))?;
+            if self.args.format == Format::Text {
+                println!("Saved answers to \"{}\".", path);
+            }
+        }
+
+        if self.args.save_timings {
+            let path = timings_path(self.args.year);
+            self.recorded
+                .save(&path)
+                .map_err(|e| format!("could not write \"{}\": {}", path, e))?;
+            if self.args.format == Format::Text {
+                println!("Saved timings to \"{}\".", path);
+            }
+        }
+
+        if !self.replaced_answers.is_empty() {
+            eprintln!(
+                "{}Replaced {} accepted answer(s):{}",
+                ANSI_BOLD,
+                self.replaced_answers.len(),
+                ANSI_RESET
+            );
+            for (name, part, previous) in &self.replaced_answers {
+                eprintln!("  {} part {}: was {}", name, part, previous);
+            }
+        }
+
+        if !self.other_mode.is_empty() {
+            let mode = if self.args.bench.is_some() {
+                "without"
+            } else {
+                "with"
+            };
+            let parts: Vec<String> = self
+                .other_mode
+                .iter()
+                .map(|(name, part)| format!("{} part {}", name, part))
+                .collect();
+            eprintln!(
+                "Not compared, the baseline was recorded {} --bench: {}",
+                mode,
+                parts.join(", ")
+            );
+        }
+
+        if !self.regressions.is_empty() {
+            eprintln!(
+                "{}{} part(s) got slower than their baseline:{}",
+                ANSI_BOLD,
+                self.regressions.len(),
+                ANSI_RESET
+            );
+            for (name, part, comparison) in &self.regressions {
+                eprintln!(
+                    "  {} part {}: {:+.1}% (baseline: {:.2?})",
+                    name,
+                    part,
+                    comparison.change * 100_f64,
+                    comparison.baseline
+                );
+            }
+        }
+
+        if !self.wrong_answers.is_empty() {
+            eprintln!(
+                "{}{} part(s) returned a wrong answer:{}",
+                ANSI_BOLD,
+                self.wrong_answers.len(),
+                ANSI_RESET
+            );
+            for (name, part, expected) in &self.wrong_answers {
+                eprintln!("  {} part {}: expected {}", name, part, expected);
+            }
+            if self.args.accept {
+                eprintln!("Pass --overwrite to replace the accepted answers.");
+            }
+        }
+
+        Ok(self.regressions.is_empty() && self.wrong_answers.is_empty())
+    }
+}
+
+/// What [`run_all`] reports once every day has run.
+pub struct Summary {
+    /// The time spent solving.
+    pub total: Duration,
+    /// `false` if any part regressed, was wrong, panicked or timed out, or a day could not run
+    /// because of its input or parameters.
+    pub passed: bool,
+    /// The timings of this run, together with the stored ones if they were saved.
+    pub timings: Timings,
+}
+
+/// Runs every given day in-process and reports its results.
+pub fn run_all(days: &[Day], args: &RunArgs) -> Result<Summary, String> {
+    let mut runner = Runner::new(args)?;
+
+    let mut records = vec![];
+    let mut failed = false;
+    let total = days
+        .iter()
+        .map(|day| {
+            let status = runner.run(day);
+            failed |= status.failed();
+            match args.format {
+                Format::Text => print_day(day, &status),
+                _ => records.extend(output::records(day, &status, args.part)),
+            }
+            status.elapsed()
+        })
+        .sum();
+
+    output::print_records(args.format, &records);
+
+    let timings = runner.recorded.clone();
+    let passed = runner.finish()?;
+    Ok(Summary {
+        total,
+        passed: passed && !failed,
+        timings,
+    })
+}
+
+pub fn print_parse(parse: &ParseResult) {
+    let timing = match &parse.stats {
+        Some(stats) => stats.to_string(),
+        None => format!("elapsed: {:.2?}", parse.elapsed),
+    };
+    println!(
+        "Parsed input {}({}{}){}",
+        ANSI_ITALIC,
+        timing,
+        format_memory(parse.memory.as_ref()),
+        ANSI_RESET
+    );
+}
+
+pub fn print_part(result: &PartResult) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+    match &result.outcome {
+        Outcome::Solved(answer) => {
+            let timing = match &result.stats {
+                Some(stats) => stats.to_string(),
+                None => format!("elapsed: {:.2?}", result.elapsed),
+            };
+            let comparison = match &result.comparison {
+                Some(c) => format!(
+                    ", baseline: {:.2?}, {:+.1}%{}",
+                    c.baseline,
+                    c.change * 100_f64,
+                    if c.regressed { " ⚠ slower" } else { "" }
+                ),
+                None => String::new(),
+            };
+            let verdict = match &result.verdict {
+                Some(Verdict::Correct) => " ✓".to_string(),
+                Some(Verdict::Wrong(expected)) => format!(" ✗ wrong, expected {}", expected),
+                Some(Verdict::Unknown) | None => String::new(),
+            };
+            // grids get their own lines, with the timing below them.
+            let answer_text = match answer {
+                Answer::Grid(grid) => format!("{}\n", grid),
+                answer => format!("{} ", answer),
+            };
+            println!(
+                "{}{}({}{}{}){}{}",
+                answer_text,
+                ANSI_ITALIC,
+                timing,
+                format_memory(result.memory.as_ref()),
+                comparison,
+                ANSI_RESET,
+                verdict
+            );
+            if let Some(reason) = answer.unread_letters() {
+                println!("⚠ the letters could not be read: {}", reason);
+            }
+        }
+        Outcome::Unsolved => {
+            println!("not solved.")
+        }
+        Outcome::Panicked(message) => {
+            println!("panicked: {}", message)
+        }
+        Outcome::TimedOut(timeout) => {
+            println!("timed out after {:.2?}.", timeout)
+        }
+        Outcome::Skipped(reason) => {
+            println!("skipped, {}.", reason)
+        }
+    }
+}
+
+fn format_memory(memory: Option<&Memory>) -> String {
+    match memory {
+        Some(memory) => format!("; {}", memory),
+        None => String::new(),
+    }
+}
+
+pub fn print_status(status: &DayStatus) {
+    match status {
+        DayStatus::Ran { parse, parts } => {
+            print_parse(parse);
+            parts.iter().for_each(print_part);
+        }
+        DayStatus::MissingInput(reason) => println!("Missing input: {}", reason),
+        DayStatus::InvalidParams(reason) => println!("Invalid parameters: {}", reason),
+    }
+}
+
+pub fn print_day(day: &Day, status: &DayStatus) {
+    let title = match day.variant {
+        Some(variant) => format!("| Day {:02} ({}) |", day.day, variant),
+        None => format!("| Day {:02} |", day.day),
+    };
+    let border = "-".repeat(title.chars().count());
+
+    println!("{}", border);
+    println!("{}{}{}", ANSI_BOLD, title, ANSI_RESET);
+    println!("{}", border);
+    print_status(status);
+}
+
+/// Runs `func` without printing the message of a panic, which is reported as part of the day's
+/// status instead.
+pub fn without_panic_output<T>(func: impl FnOnce() -> T) -> T {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = func();
+    panic::set_hook(default_hook);
+    result
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_part_outcomes() {
+        assert_eq!(
+            run_part(1, || Some("abc".len().into()), None).outcome,
+            Outcome::Solved(Answer::Number(3))
+        );
+        assert_eq!(run_part(1, || None, None).outcome, Outcome::Unsolved);
+        assert_eq!(
+            run_part(2, || panic!("malformed input"), None).outcome,
+            Outcome::Panicked("malformed input".to_string())
+        );
+    }
+
+    struct Sum;
+
+    impl solution::Solution for Sum {
+        const DAY: u8 = 1;
+        type Input<'a> = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = u32;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.lines().map(|line| line.parse().unwrap()).collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Option<u32> {
+            Some(input.iter().sum())
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Option<u32> {
+            input.iter().max().copied()
+        }
+    }
+
+    #[test]
+    fn test_run_day() {
+        let day = Day::new::<Sum>();
+        let params = Params::defaults(day.params);
+
+        let (_, parts) = run_day(&day, "1\n5\n2", &params, &RunOptions::default());
+        let outcomes: Vec<_> = parts.into_iter().map(|part| part.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                Outcome::Solved(Answer::Number(8)),
+                Outcome::Solved(Answer::Number(5))
+            ]
+        );
+
+        let options = RunOptions {
+            part: Some(2),
+            ..RunOptions::default()
+        };
+        let (_, parts) = run_day(&day, "1\n5\n2", &params, &options);
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].outcome, Outcome::Solved(Answer::Number(5)));
+
+        let (_, parts) = run_day(&day, "1\nfive", &params, &options);
+        assert_eq!(parts.len(), 1);
+        assert!(parts.iter().all(|part| matches!(
+            &part.outcome,
+            Outcome::Panicked(message) if message.starts_with("while parsing: ")
+        )));
+    }
+
+    #[test]
+    fn test_day_status_elapsed() {
+        let part = |part, outcome, nanos| PartResult {
+            part,
+            outcome,
+            elapsed: Duration::from_nanos(nanos),
+            stats: None,
+            memory: None,
+            comparison: None,
+            verdict: None,
+        };
+        let status = DayStatus::Ran {
+            parse: ParseResult {
+                elapsed: Duration::from_nanos(25),
+                stats: None,
+                memory: None,
+            },
+            parts: vec![
+                part(1, Outcome::Solved(Answer::Number(1)), 74),
+                part(2, Outcome::Unsolved, 1_000),
+            ],
+        };
+
+        // sub-microsecond timings are summed exactly, unsolved parts are not counted.
+        assert_eq!(status.elapsed(), Duration::from_nanos(99));
+        assert_eq!(
+            DayStatus::MissingInput(String::new()).elapsed(),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_day_status_failed() {
+        let status = |outcome| DayStatus::Ran {
+            parse: empty_parse(),
+            parts: vec![part_result(1, Outcome::Unsolved), part_result(2, outcome)],
+        };
+
+        assert!(!status(Outcome::Solved(Answer::Number(1))).failed());
+        assert!(status(Outcome::Panicked(String::new())).failed());
+        assert!(status(Outcome::TimedOut(Duration::ZERO)).failed());
+        assert!(DayStatus::InvalidParams(String::new()).failed());
+    }
+
+    #[test]
+    fn test_run_part_bench() {
+        let config = BenchConfig {
+            warmup: Duration::ZERO,
+            budget: Duration::from_millis(1),
+            min_samples: 3,
+            max_samples: 10,
+        };
+
+        let solved = run_part(1, || Some(Answer::from("1")), Some(&config));
+        let stats = solved.stats.expect("solved parts are benchmarked");
+        assert_eq!(solved.elapsed, stats.median);
+
+        assert!(run_part(2, || None, Some(&config)).stats.is_none());
+    }
+}
@@ -0,0 +1,290 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answer::Answer;
+use crate::input::{self, Normalize};
+use std::borrow::Cow;
+use std::cell::OnceCell;
+
+/// A solution for a single puzzle day.
+///
+/// The input is parsed once via [`Solution::parse`] and handed to both parts.
+/// Days that need the raw text in each part can use `&'a str` as their `Input`.
+pub trait Solution {
+    /// The puzzle day (1-25) this solution belongs to.
+    const DAY: u8;
+
+    /// How the input is cleaned up before [`Solution::parse`]. Defaults to converting line endings.
+    const NORMALIZE: Normalize = Normalize::LineEndings;
+
+    /// Named parameters that differ between the examples and the real input, with their real-input values.
+    const PARAMS: &'static [Param] = &[];
+
+    /// The parsed representation of the puzzle input.
+    type Input<'a>;
+    /// The answers of both parts, typically integers, a `String` or a rendered [`crate::answer::Grid`].
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    /// Parses the input with the values of [`Solution::PARAMS`].
+    /// Days with parameters override this and keep the values they need in their `Input`.
+    fn parse_with<'a>(input: &'a str, _params: &Params) -> Self::Input<'a> {
+        Self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<Self::PartOne>;
+    fn part_two(input: &Self::Input<'_>) -> Option<Self::PartTwo>;
+}
+
+/// A named puzzle parameter and its value for the real input, e.g. the row that is scanned on day 15.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    /// The values an override may take, any `i64` unless restricted with [`Param::range`].
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: i64) -> Self {
+        Param {
+            name,
+            default,
+            min: i64::MIN,
+            max: i64::MAX,
+        }
+    }
+
+    /// Restricts overrides to `min..=max`, e.g. to the range of the type the day converts to.
+    pub const fn range(self, min: i64, max: i64) -> Self {
+        Param { min, max, ..self }
+    }
+
+    /// Restricts overrides to the values of a `u32`.
+    pub const fn u32(self) -> Self {
+        self.range(0, u32::MAX as i64)
+    }
+}
+
+/// The values of a day's parameters: their defaults, overridden by an example or `--param name=value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, i64)>,
+    overridden: bool,
+}
+
+impl Params {
+    pub fn defaults(declared: &'static [Param]) -> Self {
+        Params {
+            values: declared.iter().map(|p| (p.name, p.default)).collect(),
+            overridden: false,
+        }
+    }
+
+    /// Applies overrides to the defaults. Names that the day does not declare and values outside
+    /// a parameter's range are an error.
+    pub fn new(declared: &'static [Param], overrides: &[(String, i64)]) -> Result<Self, String> {
+        let mut params = Params::defaults(declared);
+        for (name, value) in overrides {
+            match declared.iter().position(|p| p.name == name) {
+                Some(index) => {
+                    let param = &declared[index];
+                    if !(param.min..=param.max).contains(value) {
+                        return Err(format!(
+                            "parameter `{}` must be between {} and {}, got {}",
+                            name, param.min, param.max, value
+                        ));
+                    }
+                    params.values[index].1 = *value;
+                }
+                None => {
+                    let names: Vec<&str> = declared.iter().map(|p| p.name).collect();
+                    if names.is_empty() {
+                        return Err(format!(
+                            "unknown parameter `{}`, this day has no parameters",
+                            name
+                        ));
+                    }
+                    return Err(format!(
+                        "unknown parameter `{}`, expected one of: {}",
+                        name,
+                        names.join(", ")
+                    ));
+                }
+            }
+            params.overridden = true;
+        }
+        Ok(params)
+    }
+
+    /// The value of a declared parameter. Panics for names the day does not declare.
+    pub fn get(&self, name: &str) -> i64 {
+        self.values
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("parameter `{}` is not declared in `PARAMS`", name))
+    }
+
+    /// Whether any value differs from the real-input defaults, in which case answers are not checked.
+    pub fn is_overridden(&self) -> bool {
+        self.overridden
+    }
+}
+
+/// Parses `name=value`, as passed to `--param`.
+pub fn parse_param(value: &str) -> Result<(String, i64), String> {
+    let (name, value) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got \"{}\"", value))?;
+    let value = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("value of parameter `{}` is not an integer", name.trim()))?;
+    Ok((name.trim().to_string(), value))
+}
+
+/// Receives the steps of a run of a type-erased day, see [`Day::run`].
+///
+/// The parsed input never leaves the day, so the visitor only gets closures that
+/// parse the input and solve a part. Each closure may be called more than once, e.g. to benchmark it.
+pub trait Visitor {
+    fn parse(&mut self, parse: &dyn Fn());
+    /// Only called if parsing succeeded, with the parsed input shared between both parts.
+    fn part(&mut self, part: u8, solve: &dyn Fn() -> Option<Answer>);
+}
+
+/// A type-erased [`Solution`], as stored in the day registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    /// Alternative implementations of a day are registered under a variant name.
+    pub variant: Option<&'static str>,
+    pub normalize: Normalize,
+    pub params: &'static [Param],
+    run: fn(&str, &Params, &mut dyn Visitor),
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            variant: None,
+            normalize: S::NORMALIZE,
+            params: S::PARAMS,
+            run: erased_run::<S>,
+        }
+    }
+
+    pub const fn with_variant(mut self, variant: &'static str) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// The name of the binary for this day, e.g. `05` or `05_sub_optimal`.
+    pub fn name(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("{:02}_{}", self.day, variant),
+            None => format!("{:02}", self.day),
+        }
+    }
+
+    /// Applies the day's [`Normalize`] policy to a raw input.
+    pub fn prepare<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        input::normalize(input, self.normalize)
+    }
+
+    /// Parses the input once and solves both parts with it, reporting each step to `visitor`.
+    pub fn run(&self, input: &str, params: &Params, visitor: &mut dyn Visitor) {
+        (self.run)(input, params, visitor)
+    }
+
+    pub fn part_one(&self, input: &str, params: &Params) -> Option<Answer> {
+        self.solve(1, input, params)
+    }
+
+    pub fn part_two(&self, input: &str, params: &Params) -> Option<Answer> {
+        self.solve(2, input, params)
+    }
+
+    fn solve(&self, part: u8, input: &str, params: &Params) -> Option<Answer> {
+        let mut single = Single { part, answer: None };
+        self.run(input, params, &mut single);
+        single.answer
+    }
+}
+
+/// Solves a single part, without any timing.
+struct Single {
+    part: u8,
+    answer: Option<Answer>,
+}
+
+impl Visitor for Single {
+    fn parse(&mut self, parse: &dyn Fn()) {
+        parse()
+    }
+
+    fn part(&mut self, part: u8, solve: &dyn Fn() -> Option<Answer>) {
+        if part == self.part {
+            self.answer = solve();
+        }
+    }
+}
+
+fn erased_run<S: Solution>(input: &str, params: &Params, visitor: &mut dyn Visitor) {
+    let parsed = OnceCell::new();
+    // repeated calls parse again, but keep the first parsed input.
+    visitor.parse(&|| {
+        let _ = parsed.set(S::parse_with(input, params));
+    });
+
+    if let Some(parsed) = parsed.get() {
+        visitor.part(1, &|| S::part_one(parsed).map(Into::into));
+        visitor.part(2, &|| S::part_two(parsed).map(Into::into));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[Param::new("row", 2_000_000), Param::new("bound", 4_000_000)];
+
+    #[test]
+    fn test_params() {
+        let defaults = Params::defaults(PARAMS);
+        assert_eq!(defaults.get("row"), 2_000_000);
+        assert!(!defaults.is_overridden());
+
+        let params = Params::new(PARAMS, &[("row".to_string(), 10)]).unwrap();
+        assert_eq!((params.get("row"), params.get("bound")), (10, 4_000_000));
+        assert!(params.is_overridden());
+
+        assert_eq!(
+            Params::new(PARAMS, &[("rows".to_string(), 10)]).unwrap_err(),
+            "unknown parameter `rows`, expected one of: row, bound"
+        );
+        assert!(Params::new(&[], &[("row".to_string(), 10)]).is_err());
+
+        const RANGED: &[Param] = &[Param::new("size", 10).u32()];
+        assert!(Params::new(RANGED, &[("size".to_string(), 4_294_967_295)]).is_ok());
+        assert_eq!(
+            Params::new(RANGED, &[("size".to_string(), -1)]).unwrap_err(),
+            "parameter `size` must be between 0 and 4294967295, got -1"
+        );
+        assert!(Params::new(RANGED, &[("size".to_string(), 4_294_967_296)]).is_err());
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(parse_param("row=10"), Ok(("row".to_string(), 10)));
+        assert_eq!(parse_param("bound = -20"), Ok(("bound".to_string(), -20)));
+        assert!(parse_param("row").is_err());
+        assert!(parse_param("row=ten").is_err());
+    }
+}
@@ -0,0 +1,50 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::crate_path;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A versioned JSON file below `data/`, such as the timing baselines or the known answers.
+/// Relative paths are resolved against the crate root, like inputs.
+pub trait Store: Serialize + DeserializeOwned + Default {
+    const VERSION: u32;
+
+    fn version(&self) -> u32;
+
+    /// Loads the store. A missing file is treated as an empty store.
+    fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = crate_path(path);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(format!("could not read \"{}\": {}", path.display(), e)),
+        };
+
+        let store: Self = serde_json::from_str(&contents)
+            .map_err(|e| format!("could not parse \"{}\": {}", path.display(), e))?;
+
+        if store.version() != Self::VERSION {
+            return Err(format!(
+                "\"{}\" has version {}, expected version {}",
+                path.display(),
+                store.version(),
+                Self::VERSION
+            ));
+        }
+
+        Ok(store)
+    }
+
+    fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = crate_path(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+    }
+}
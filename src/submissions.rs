@@ -0,0 +1,256 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::store::Store;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub fn submissions_path(year: i16) -> String {
+    format!("data/{}/submissions.json", year)
+}
+
+/// How the server responded to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was submitted too soon after a previous attempt and was not checked.
+    RateLimited,
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    Unknown,
+}
+
+impl SubmitOutcome {
+    /// Classifies the text of the server's response.
+    pub fn from_response(text: &str) -> Self {
+        if text.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if text.contains("You gave an answer too recently") {
+            SubmitOutcome::RateLimited
+        } else if text.contains("Did you already complete it") {
+            SubmitOutcome::AlreadySolved
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                SubmitOutcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                SubmitOutcome::TooLow
+            } else {
+                SubmitOutcome::Wrong
+            }
+        } else {
+            SubmitOutcome::Unknown
+        }
+    }
+
+    /// Whether the answer was checked and rejected.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            SubmitOutcome::TooHigh | SubmitOutcome::TooLow | SubmitOutcome::Wrong
+        )
+    }
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome = match self {
+            SubmitOutcome::Correct => "correct",
+            SubmitOutcome::TooHigh => "too high",
+            SubmitOutcome::TooLow => "too low",
+            SubmitOutcome::Wrong => "wrong",
+            SubmitOutcome::RateLimited => "rate limited",
+            SubmitOutcome::AlreadySolved => "already solved",
+            SubmitOutcome::Unknown => "unknown",
+        };
+        write!(f, "{}", outcome)
+    }
+}
+
+/// A single submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+    /// The text of the server's response.
+    pub message: String,
+    /// Seconds since the unix epoch.
+    pub submitted_at: u64,
+}
+
+impl Attempt {
+    pub fn new(answer: &str, outcome: SubmitOutcome, message: &str) -> Self {
+        Attempt {
+            answer: answer.to_string(),
+            outcome,
+            message: message.to_string(),
+            submitted_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Why an answer is likely wrong, judging by previous attempts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// The same answer was submitted before and was wrong.
+    KnownWrong(SubmitOutcome),
+    /// The answer is not below a previous answer that was too high.
+    AboveTooHigh(String),
+    /// The answer is not above a previous answer that was too low.
+    BelowTooLow(String),
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::KnownWrong(outcome) => {
+                write!(f, "this answer was already submitted and was {}", outcome)
+            }
+            Rejection::AboveTooHigh(bound) => {
+                write!(f, "{} was too high, this answer is not lower", bound)
+            }
+            Rejection::BelowTooLow(bound) => {
+                write!(f, "{} was too low, this answer is not higher", bound)
+            }
+        }
+    }
+}
+
+/// Every submitted answer keyed by day (e.g. `05`) and part, as stored in `data/<year>/submissions.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submissions {
+    pub version: u32,
+    pub days: BTreeMap<String, BTreeMap<u8, Vec<Attempt>>>,
+}
+
+impl Default for Submissions {
+    fn default() -> Self {
+        Submissions {
+            version: <Submissions as Store>::VERSION,
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+impl Store for Submissions {
+    const VERSION: u32 = 1;
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+impl Submissions {
+    pub fn attempts(&self, day: u8, part: u8) -> &[Attempt] {
+        self.days
+            .get(&format!("{:02}", day))
+            .and_then(|parts| parts.get(&part))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, attempt: Attempt) {
+        self.days
+            .entry(format!("{:02}", day))
+            .or_default()
+            .entry(part)
+            .or_default()
+            .push(attempt);
+    }
+
+    /// Checks an answer against previous attempts before submitting it.
+    ///
+    /// Bounds are only checked for numeric answers.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<(), Rejection> {
+        let attempts = self.attempts(day, part);
+
+        if let Some(attempt) = attempts
+            .iter()
+            .find(|attempt| attempt.answer == answer && attempt.outcome.is_wrong())
+        {
+            return Err(Rejection::KnownWrong(attempt.outcome));
+        }
+
+        let value = match answer.parse::<i128>() {
+            Ok(value) => value,
+            Err(_) => return Ok(()),
+        };
+
+        for attempt in attempts {
+            let bound = match attempt.answer.parse::<i128>() {
+                Ok(bound) => bound,
+                Err(_) => continue,
+            };
+            match attempt.outcome {
+                SubmitOutcome::TooHigh if value >= bound => {
+                    return Err(Rejection::AboveTooHigh(attempt.answer.clone()))
+                }
+                SubmitOutcome::TooLow if value <= bound => {
+                    return Err(Rejection::BelowTooLow(attempt.answer.clone()))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_from_response() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer to finding the sleigh keys.", SubmitOutcome::Correct),
+            ("That's not the right answer; your answer is too high. If you're stuck, ...", SubmitOutcome::TooHigh),
+            ("That's not the right answer; your answer is too low. Please wait one minute ...", SubmitOutcome::TooLow),
+            ("That's not the right answer. If you're stuck, make sure you're using the full input data ...", SubmitOutcome::Wrong),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 34s left to wait.", SubmitOutcome::RateLimited),
+            ("You don't seem to be solving the right level.  Did you already complete it? [Return to Day 1]", SubmitOutcome::AlreadySolved),
+            ("Please don't repeatedly request this endpoint before it unlocks!", SubmitOutcome::Unknown),
+        ];
+        for (text, outcome) in cases {
+            assert_eq!(SubmitOutcome::from_response(text), outcome, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut submissions = Submissions::default();
+        submissions.record(1, 1, Attempt::new("100", SubmitOutcome::TooHigh, ""));
+        submissions.record(1, 1, Attempt::new("10", SubmitOutcome::TooLow, ""));
+        submissions.record(1, 1, Attempt::new("50", SubmitOutcome::Wrong, ""));
+        submissions.record(1, 1, Attempt::new("60", SubmitOutcome::RateLimited, ""));
+
+        assert_eq!(
+            submissions.check(1, 1, "50"),
+            Err(Rejection::KnownWrong(SubmitOutcome::Wrong))
+        );
+        assert_eq!(
+            submissions.check(1, 1, "100"),
+            Err(Rejection::KnownWrong(SubmitOutcome::TooHigh))
+        );
+        assert_eq!(
+            submissions.check(1, 1, "150"),
+            Err(Rejection::AboveTooHigh("100".to_string()))
+        );
+        assert_eq!(
+            submissions.check(1, 1, "5"),
+            Err(Rejection::BelowTooLow("10".to_string()))
+        );
+        assert_eq!(submissions.check(1, 1, "60"), Ok(()));
+        assert_eq!(submissions.check(1, 1, "CMZ"), Ok(()));
+        assert_eq!(submissions.check(1, 2, "150"), Ok(()));
+    }
+}
@@ -0,0 +1,131 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::store::Store;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+pub fn timings_path(year: i16) -> String {
+    format!("data/{}/timings.json", year)
+}
+
+/// A stored timing for a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub elapsed_ns: u64,
+    /// Whether the timing is a `--bench` median rather than a single run.
+    pub bench: bool,
+}
+
+impl Timing {
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_ns)
+    }
+}
+
+/// Timings keyed by day name (e.g. `05` or `05_sub_optimal`) and part, as stored in `data/<year>/timings.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timings {
+    pub version: u32,
+    pub days: BTreeMap<String, BTreeMap<u8, Timing>>,
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        Timings {
+            version: <Timings as Store>::VERSION,
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+impl Store for Timings {
+    const VERSION: u32 = 1;
+
+    fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+impl Timings {
+    pub fn get(&self, name: &str, part: u8) -> Option<&Timing> {
+        self.days.get(name).and_then(|parts| parts.get(&part))
+    }
+
+    pub fn insert(&mut self, name: &str, part: u8, timing: Timing) {
+        self.days
+            .entry(name.to_string())
+            .or_default()
+            .insert(part, timing);
+    }
+}
+
+/// The result of comparing a part's timing to its stored baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub baseline: Duration,
+    /// The relative change, e.g. `0.5` for a part that got 50% slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Flags `current` as a regression if it is slower than `baseline` by more than `threshold`.
+    pub fn new(current: Duration, baseline: Duration, threshold: f64) -> Self {
+        let change = if baseline.is_zero() {
+            0_f64
+        } else {
+            current.as_secs_f64() / baseline.as_secs_f64() - 1_f64
+        };
+
+        Comparison {
+            baseline,
+            change,
+            regressed: change > threshold,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_comparison() {
+        let baseline = Duration::from_millis(10);
+
+        let slower = Comparison::new(Duration::from_millis(20), baseline, 0.1);
+        assert!(slower.regressed);
+        assert!((slower.change - 1_f64).abs() < 1.0e-9);
+
+        assert!(!Comparison::new(Duration::from_millis(11), baseline, 0.2).regressed);
+        assert!(!Comparison::new(Duration::from_millis(5), baseline, 0.1).regressed);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc_timings_{}.json", std::process::id()));
+
+        assert_eq!(Timings::load(&path).unwrap(), Timings::default());
+
+        let mut timings = Timings::default();
+        let timing = Timing {
+            elapsed_ns: 1_500,
+            bench: true,
+        };
+        timings.insert("15", 2, timing);
+        timings.save(&path).unwrap();
+
+        let loaded = Timings::load(&path).unwrap();
+        assert_eq!(loaded.get("15", 2), Some(&timing));
+        assert_eq!(loaded.get("15", 1), None);
+
+        fs::write(&path, r#"{"version": 99, "days": {}}"#).unwrap();
+        assert!(Timings::load(&path).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,57 @@
+use crate::solution::Solution;
+use std::collections::BinaryHeap;
+
+pub fn get_total_calories(input: &str) -> Vec<u32> {
+    let total_calories = input
+        .split("\n\n")
+        .map(|inventory| inventory.lines().map(|c| c.parse::<u32>().unwrap()).sum())
+        .collect::<Vec<u32>>();
+
+    total_calories
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        get_total_calories(input)
+    }
+
+    fn part_one(total_calories: &Self::Input<'_>) -> Option<u32> {
+        total_calories.iter().max().copied()
+    }
+
+    fn part_two(total_calories: &Self::Input<'_>) -> Option<u32> {
+        let mut heap = total_calories.iter().copied().collect::<BinaryHeap<u32>>();
+        let mut top_three = Vec::new();
+        for _ in 0..3 {
+            if let Some(v) = heap.pop() {
+                top_three.push(v);
+            }
+        }
+
+        Some(top_three.iter().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 1);
+        assert_eq!(Day01::part_one(&Day01::parse(&input)), Some(24000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 1);
+        assert_eq!(Day01::part_two(&Day01::parse(&input)), Some(45000));
+    }
+}
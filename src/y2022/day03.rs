@@ -0,0 +1,108 @@
+use crate::solution::Solution;
+use array_tool::vec::Intersect;
+
+const UPPERCASE_DIFF: u8 = 38;
+const UPPERCASE_Z: u8 = 52;
+const LOWERCASE_DIFF: u8 = 58;
+
+
+pub fn parse(input: &str) -> Vec<&str> {
+    let rucksacks = input
+        .split('\n')
+        .filter(|rucksack| !rucksack.is_empty())
+        .collect();
+
+    rucksacks
+}
+
+pub fn score_item(item: &str) -> u32 {
+    let mut scored_item = item.as_bytes()[0] - UPPERCASE_DIFF;
+    if scored_item > UPPERCASE_Z {
+        scored_item -= LOWERCASE_DIFF;
+    }
+    scored_item as u32
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(rucksacks: &Self::Input<'_>) -> Option<u32> {
+        let count = rucksacks
+            .iter()
+            .map(|rucksack| {
+                let midpoint = rucksack.len() / 2 ;
+                let split_rucksack = rucksack.split_at(midpoint);
+                vec![split_rucksack.0, split_rucksack.1]
+            })
+            .map(|rucksack| {
+                let shared_item = rucksack[0]
+                    .split("")
+                    .filter(|letter| !letter.is_empty())
+                    .collect::<Vec<&str>>()
+                    .intersect(rucksack[1]
+                        .split("")
+                        .filter(|letter| !letter.is_empty())
+                        .collect()
+                    )[0];
+                score_item(shared_item)
+
+            })
+            .sum();
+        Some(count)
+    }
+
+    fn part_two(rucksacks: &Self::Input<'_>) -> Option<u32> {
+        let count = rucksacks
+            .chunks(3)
+            .map(|chunk| {
+                let shared_items1 = chunk[0]
+                    .split("")
+                    .filter(|letter| !letter.is_empty())
+                    .collect::<Vec<&str>>()
+                    .intersect(chunk[1]
+                        .split("")
+                        .filter(|letter| !letter.is_empty())
+                        .collect()
+                    );
+                let shared_items2 = chunk[1]
+                    .split("")
+                    .filter(|letter| !letter.is_empty())
+                    .collect::<Vec<&str>>()
+                    .intersect(chunk[2]
+                        .split("")
+                        .filter(|letter| !letter.is_empty())
+                        .collect()
+                    );
+                let shared_item = shared_items1.intersect(shared_items2)[0];
+                score_item(shared_item)
+            })
+            .sum();
+        Some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 3);
+        assert_eq!(Day03::part_one(&Day03::parse(&input)), Some(157));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 3);
+        assert_eq!(Day03::part_two(&Day03::parse(&input)), Some(70));
+    }
+}
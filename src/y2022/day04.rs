@@ -0,0 +1,78 @@
+use crate::solution::Solution;
+
+pub fn parse(input: &str) -> Vec<Vec<u32>> {
+    let sectors = input
+        .split('\n')
+        .filter(|sector| !sector.is_empty())
+        .map(|sector| {
+            let mapped_sector = sector
+                .split(',')
+                .collect::<Vec<&str>>()
+                .iter()    
+                .flat_map(|subsector| {
+                    subsector.split('-')
+                        .map(|c| c.parse::<u32>().unwrap())
+                        .collect::<Vec<u32>>()
+                })
+                .collect::<Vec<u32>>();
+
+            mapped_sector
+        })
+        .collect();
+
+    sectors
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input<'a> = Vec<Vec<u32>>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(sectors: &Self::Input<'_>) -> Option<u32> {
+        let count = sectors
+           .iter()
+           .map(|sector_ranges| {
+                u32::from((sector_ranges[0] <= sector_ranges[2] && sector_ranges[1] >= sector_ranges[3]) || (sector_ranges[2] <= sector_ranges[0] && sector_ranges[3] >= sector_ranges[1]))
+           })
+           .sum();
+
+        Some(count)
+    }
+
+    fn part_two(sectors: &Self::Input<'_>) -> Option<u32> {
+        let count = sectors
+           .iter()
+           .map(|sector_range| {
+                let range1 = sector_range[0]..=sector_range[1];
+                let range2 = sector_range[2]..=sector_range[3];
+                u32::from(range2.contains(&sector_range[0]) || range2.contains(&sector_range[1]) || range1.contains(&sector_range[2]) || range1.contains(&sector_range[3]))
+           })
+           .sum();
+
+        Some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 4);
+        assert_eq!(Day04::part_one(&Day04::parse(&input)), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 4);
+        assert_eq!(Day04::part_two(&Day04::parse(&input)), Some(4));
+    }
+}
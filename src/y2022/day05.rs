@@ -0,0 +1,117 @@
+use crate::solution::Solution;
+
+pub fn parse(input: &str) -> Vec<&str> {
+    // Input is separated by 2 newlines, dividing the stacks of crates
+    // and the directions for moving the crates
+    input
+        .split("\n\n")
+        .filter(|section| !section.is_empty())
+        .collect()
+}
+
+pub fn get_crates(block: &str) -> Vec<Vec<char>> {
+    let rows: Vec<&str> = block
+        .split('\n')
+        .filter(|section| !section.is_empty())
+        .collect();
+    let number_of_stacks = (rows[0].len() + 1) / 4;
+    let mut boxes = vec![Vec::<char>::new(); number_of_stacks];
+
+    rows
+        .iter()
+        .for_each(|row| {
+            row.chars().enumerate().for_each(|(index, crate_label)| {
+                if crate_label.is_ascii_alphabetic() {
+                    boxes[index / 4].insert(0, crate_label)
+                }
+            });
+        });
+
+    boxes
+
+}
+
+pub fn get_directions(directions: &str) -> Vec<Vec<u32>> {
+    // Since the directions follow the format "move N from S to E",
+    // we can just grab every 2nd element when split by whitespace
+    directions
+        .lines()
+        .filter(|section| !section.is_empty())
+        .map(|row| {
+            row
+                .split_whitespace()
+                .skip(1)
+                .step_by(2)
+                .map(|num_str| num_str.parse::<u32>().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+pub fn move_crates(sections: &[&str], keep_order: bool) -> Vec<Vec<char>> {
+    let mut stacks = get_crates(sections[0]);
+    let directions = get_directions(sections[1]);
+    // Following the format "move N from S to E", we take the last N elements from stacks[S] and
+    // move them to stacks[E]
+    directions.iter().for_each(|row| {
+        let final_length = stacks[(row[1] - 1) as usize].len().saturating_sub(row[0] as usize);
+        let mut tail = stacks[(row[1] - 1) as usize].split_off(final_length);
+        if !keep_order {
+            tail.reverse();
+        }
+        stacks[(row[2] - 1) as usize].extend(tail);
+    });
+
+    stacks
+}
+
+pub fn get_top_crates(stacks: Vec<Vec<char>>) -> String {
+    stacks
+        .iter()    
+        .map(|stack| {
+            stack.last().unwrap().to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(sections: &Self::Input<'_>) -> Option<String> {
+        let stacks = move_crates(sections, false);
+        Some(get_top_crates(stacks))
+
+    }
+
+    fn part_two(sections: &Self::Input<'_>) -> Option<String> {
+        let stacks = move_crates(sections, true);
+        Some(get_top_crates(stacks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 5);
+        assert_eq!(Day05::part_one(&Day05::parse(&input)), Some("CMZ".to_owned()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 5);
+        assert_eq!(Day05::part_two(&Day05::parse(&input)), Some("MCD".to_owned()));
+    }
+}
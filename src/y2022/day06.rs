@@ -0,0 +1,63 @@
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+
+pub fn parse(input: &str) -> &str {
+    input
+        .lines()
+        .collect::<Vec<&str>>()[0]
+}
+
+pub fn get_start_of_message_marker(datastream: &str, offset: usize) -> u32 {
+    let mut sequence_length: usize = 0;
+    let packet_markers = datastream
+        .chars()
+        .collect::<Vec<char>>();
+
+    for (index, window) in packet_markers.windows(offset).enumerate() {
+        if window.iter().collect::<HashSet<_>>().len() == offset {
+            sequence_length = index + offset;
+            break;
+        }
+    }
+
+    sequence_length as u32
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input<'a> = &'a str;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(datastream: &Self::Input<'_>) -> Option<u32> {
+        Some(get_start_of_message_marker(datastream, 4))
+    }
+
+    fn part_two(datastream: &Self::Input<'_>) -> Option<u32> {
+        Some(get_start_of_message_marker(datastream, 14))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 6);
+        assert_eq!(Day06::part_one(&Day06::parse(&input)), Some(5));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 6);
+        assert_eq!(Day06::part_two(&Day06::parse(&input)), Some(23));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::solution::{Param, Params, Solution};
+
+#[derive(Debug, PartialEq)]
+enum Type {
+    Directory,
+    File
+}
+
+// Inspired by https://dev.to/deciduously/no-more-tears-no-more-knots-arena-allocated-trees-in-rust-44k6
+#[derive(Debug)]
+struct Node<T>
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    idx: usize,
+    name: T,
+    node_size: u32,
+    file_type: Option<Type>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+impl<T> Node<T>
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    fn new(idx: usize, name: T, node_size: u32, file_type: Option<Type>) -> Self {
+        Self {
+            idx,
+            name,
+            node_size,
+            file_type,
+            parent: None,
+            children: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ArenaTree<T>
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    arena: Vec<Node<T>>,
+}
+
+impl<T> ArenaTree<T>
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    fn node(&mut self, name: T, node_size: u32, file_type: Option<Type>) -> usize {
+        //first see if it exists
+        for node in &self.arena {
+            if node.name == name {
+                return node.idx;
+            }
+        }
+        // Otherwise, add new node
+        let idx = self.arena.len();
+        self.arena.push(Node::new(idx, name, node_size, file_type));
+        idx
+    }
+
+    fn get_directory_size(&self, idx: usize) -> u32 {
+        let mut ret = 0;
+        for p in &self.arena[idx].children {
+            ret += self.get_directory_size(*p);
+        }
+        ret + self.arena[idx].node_size
+    }
+
+    fn get_directories(&self) -> Vec<&Node<T>> {
+        self.arena
+            .iter()
+            .filter(|node| {
+                node.file_type == Some(Type::Directory)
+            })
+            .collect::<Vec<&Node<T>>>()
+    }
+}
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input
+        .lines()
+        .skip(2)
+        .collect::<Vec<&str>>()
+}
+
+pub fn get_tree(input: &str) -> ArenaTree<String> {
+    let mut tree: ArenaTree<String> = ArenaTree::default();
+    let root = tree.node("/".into(), 0, Some(Type::Directory));
+    let mut pwd = root;
+    let mut current_dir = root;
+    for line in parse(input) {
+        let mut split_line: Vec<&str> = line.split_whitespace().collect();
+        split_line = split_line[(split_line.len() - 2)..].to_vec();
+        match split_line[0] {
+            "dir" => {
+                let dir = tree.node(
+                    format!("{}{}/", tree.arena[pwd].name, split_line[1]),
+                    0,
+                    Some(Type::Directory)
+                );
+                tree.arena[pwd].children.push(dir);
+                tree.arena[dir].parent = Some(pwd);
+            },
+            "cd" => {
+                if split_line[1] == ".." {
+                    current_dir = tree.arena[current_dir].parent.unwrap();
+                } else {
+                    let dir = tree.node(
+                        format!("{}{}/", tree.arena[pwd].name, split_line[1]),
+                        0,
+                        Some(Type::Directory)
+                    );
+                    current_dir = dir;
+                }
+                pwd = current_dir;
+            },
+            "$" => (),
+            &_ => {
+                let file_size = split_line[0].parse::<u32>().unwrap();
+                let file = tree.node(
+                    format!("{}{}", tree.arena[current_dir].name, split_line[1]),
+                    file_size,
+                    Some(Type::File)
+                );
+                tree.arena[current_dir].children.push(file);
+                tree.arena[file].parent = Some(current_dir);
+            }
+        }
+    }
+    tree
+}
+
+pub struct Filesystem {
+    tree: ArenaTree<String>,
+    disk_capacity: u32,
+    space_needed: u32,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const PARAMS: &'static [Param] = &[
+        Param::new("disk_capacity", 70_000_000).u32(),
+        Param::new("space_needed", 30_000_000).u32(),
+    ];
+    type Input<'a> = Filesystem;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Self::Input<'a> {
+        Filesystem {
+            tree: get_tree(input),
+            disk_capacity: u32::try_from(params.get("disk_capacity"))
+                .expect("overrides are checked against the range of the parameter"),
+            space_needed: u32::try_from(params.get("space_needed"))
+                .expect("overrides are checked against the range of the parameter"),
+        }
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<u32> {
+        let tree = &input.tree;
+        let directories = tree.get_directories();
+        let directory_sizes = directories
+            .iter()
+            .map(|node| {
+                tree.get_directory_size(node.idx)
+            })
+            .filter(|directory_size| {
+                *directory_size <= 100_000
+            })
+            .collect::<Vec<u32>>();
+
+        Some(directory_sizes.iter().sum())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<u32> {
+        let tree = &input.tree;
+        // with `--param`, the disk may be too small to hold the files, or already have enough space.
+        let unused_disk_space = input.disk_capacity.checked_sub(tree.get_directory_size(0))?;
+        let needed_disk_space = input.space_needed.saturating_sub(unused_disk_space);
+        let directories = tree.get_directories();
+        let directory_sizes = directories
+            .iter()
+            .map(|node| {
+                tree.get_directory_size(node.idx)
+            })
+            .filter(|directory_size| {
+                *directory_size >= needed_disk_space
+            })
+            .collect::<Vec<u32>>();
+
+        directory_sizes.iter().min().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 7);
+        assert_eq!(Day07::part_one(&Day07::parse(&input)), Some(95437));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 7);
+        assert_eq!(Day07::part_two(&Day07::parse(&input)), Some(24_933_642));
+    }
+
+    #[test]
+    fn test_part_two_params() {
+        let input = crate::read_file("examples", 2022, 7);
+        let parse = |name: &str, value| {
+            let params = Params::new(Day07::PARAMS, &[(name.to_string(), value)]).unwrap();
+            Day07::parse_with(&input, &params)
+        };
+        assert_eq!(Day07::part_two(&parse("disk_capacity", 10)), None);
+        assert_eq!(Day07::part_two(&parse("space_needed", 100_000_000)), None);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::answer::{Answer, Grid};
+use crate::helpers;
+use crate::solution::Solution;
+
+pub struct Signal {
+    pub cycles: i32,
+    pub value: i32,
+}
+
+fn parse_input(input: &str) -> Vec<Signal> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split_whitespace())
+        .map(|mut line| {
+            let instruction = line.next().unwrap();
+            let value = match line.next() {
+                Some(value) => value.parse::<i32>().unwrap_or(0_i32),
+                None => 0_i32,
+            };
+            match instruction {
+                "noop" => Signal { cycles: 1, value },
+                "addx" => Signal { cycles: 2, value },
+                _ => panic!("Invalid instruction"),
+            }
+        })
+        .collect()
+}
+
+/// Draws the CRT screen, one row of 40 pixels per 40 cycles.
+fn render_crt(signals: &[Signal]) -> Grid {
+    let mut crt: Vec<Vec<bool>> = vec![vec![]; 6];
+    let mut pixel: i32 = 0;
+    let mut registry: i32 = 1;
+    let mut cycle = 0;
+    let mut row;
+
+    for signal in signals {
+        for _ in 0..signal.cycles {
+            row = cycle / 40;
+            cycle += 1;
+            crt[row].push((registry - pixel).abs() <= 1);
+            pixel += 1;
+            pixel %= 40;
+        }
+        registry += signal.value;
+    };
+
+    Grid::from_pixels(crt)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = Vec<Signal>;
+    type PartOne = i32;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input)
+    }
+
+    fn part_one(signals: &Self::Input<'_>) -> Option<i32> {
+        let cycles = [20, 60, 100, 140, 180, 220];
+        let mut registry = 1;
+        let mut cycle = 0;
+        let mut signal_strengths: Vec<i32> = vec![];
+
+        for signal in signals {
+            for _ in 0..signal.cycles {
+                cycle += 1;
+                if cycles.contains(&cycle) {
+                    signal_strengths.push(registry * cycle);
+                }
+            }
+            if cycle > 220 {
+                // println!("Above 220");
+                break;
+            }
+            registry += signal.value;
+        };
+
+        Some(signal_strengths.iter().sum::<i32>())
+    }
+
+    fn part_two(signals: &Self::Input<'_>) -> Option<Answer> {
+        let crt = render_crt(signals);
+        // the example does not spell any letters, so the picture is the answer then. The runner
+        // warns about pictures that cannot be read, see `Answer::unread_letters`.
+        match helpers::ocr(crt.rows()) {
+            Ok(letters) => Some(Answer::Text(letters)),
+            Err(_) => Some(Answer::Grid(crt)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 10);
+        assert_eq!(Day10::part_one(&Day10::parse(&input)), Some(13140));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 10);
+        let crt = render_crt(&Day10::parse(&input));
+        assert_eq!(
+            crt.rows(),
+            [
+                "##..##..##..##..##..##..##..##..##..##..",
+                "###...###...###...###...###...###...###.",
+                "####....####....####....####....####....",
+                "#####.....#####.....#####.....#####.....",
+                "######......######......######......####",
+                "#######.......#######.......#######.....",
+            ]
+        );
+        assert_eq!(
+            Day10::part_two(&Day10::parse(&input)),
+            Some(Answer::Grid(crt))
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::solution::Solution;
+use itertools::Itertools;
+use serde_json::Value;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl TryFrom<Value> for Packet {
+    type Error = String;
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(num) => Ok(Self::Integer(num.as_u64().unwrap() as u32)),
+            Value::Array(arr) => Ok(Self::List(arr.iter().map(|v| Packet::try_from(v.clone()).unwrap()).collect())),
+            _ => Err("Invalid Packet".to_owned()),
+        }
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(left_packet_num), Self::Integer(right_packet_num)) => left_packet_num.cmp(right_packet_num),
+            (Self::Integer(_), Self::List(_)) => Self::List(vec![self.clone()]).cmp(other),
+            (Self::List(_), Self::Integer(_)) => self.cmp(&Self::List(vec![other.clone()])),
+            (Self::List(left_packet_list), Self::List(right_packet_list)) => {
+                for (left_packet_num, right_packet_num) in left_packet_list.iter().zip(right_packet_list) {
+                    if left_packet_num.cmp(right_packet_num) != Ordering::Equal {
+                        return left_packet_num.cmp(right_packet_num);
+                    }
+                }
+                left_packet_list.len().cmp(&right_packet_list.len())
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Packet> {
+    input.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let packet_json = serde_json::from_str::<Value>(line).unwrap();
+            Packet::try_from(packet_json).unwrap()
+        })
+        .collect::<Vec<_>>()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input<'a> = Vec<Packet>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(signals: &Self::Input<'_>) -> Option<u32> {
+        let pair_sums: u32 = signals.iter()
+            .tuples()
+            .positions(|(a,b)| a.cmp(b) != Ordering::Greater)
+            .map(|i| i as u32 + 1)
+            .sum();
+        Some(pair_sums)
+    }
+
+    fn part_two(signals: &Self::Input<'_>) -> Option<u32> {
+        let mut signals = signals.clone();
+        let beacons = [
+            Packet::try_from(serde_json::from_str::<Value>("[[2]]").unwrap()).unwrap(),
+            Packet::try_from(serde_json::from_str::<Value>("[[6]]").unwrap()).unwrap(),
+        ];
+        signals.extend(beacons.iter().cloned());
+        signals.sort();
+        let decoder_key = signals.iter().positions(|b| beacons.contains(b)).map(|i| i as u32 + 1).product();
+
+        Some(decoder_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 13);
+        assert_eq!(Day13::part_one(&Day13::parse(&input)), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 13);
+        assert_eq!(Day13::part_two(&Day13::parse(&input)), Some(140));
+    }
+}
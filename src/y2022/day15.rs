@@ -0,0 +1,185 @@
+// only looking at sensors with a beacon that has y=2_000_000
+// calculate manhattan distance for each sensor to it's corresponding beacon
+// add coordinates in row 2_000_000 to a set that are within that manhattan distance
+
+use crate::solution::{Param, Params, Solution};
+use std::collections::HashSet;
+
+pub struct Sensor {
+    pub x: i64,
+    pub y: i64,
+    pub radius: i64,
+}
+
+pub struct Beacon {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Sensor {
+    pub fn manhattan_dist(&self, other: &Beacon) -> i64 {
+        (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as i64
+    }
+}
+
+pub fn manhattan_dist(sensor: &Sensor, beacon: &Beacon) -> i64 {
+    (sensor.x.abs_diff(beacon.x) + sensor.y.abs_diff(beacon.y)) as i64
+}
+
+pub struct Tunnels {
+    pub network: Vec<(Sensor, Beacon)>,
+    /// The row that is scanned in part one.
+    pub row: i64,
+    /// The largest coordinate the distress beacon can have in part two.
+    pub bound: i64,
+}
+
+pub fn get_device_coordinates(input: &str) -> (i64, i64) {
+    let device_split = input.split(" at ").last().unwrap();
+    let x = device_split
+        .split(", ")
+        .next()
+        .unwrap()
+        .split('=')
+        .next_back()
+        .unwrap()
+        .parse::<i64>()
+        .unwrap();
+    let y = device_split
+        .split(", ")
+        .last()
+        .unwrap()
+        .split('=')
+        .next_back()
+        .unwrap()
+        .parse::<i64>()
+        .unwrap();
+
+    (x, y)
+}
+
+pub fn parse(input: &str, params: &Params) -> Tunnels {
+    let mut network: Vec<(Sensor, Beacon)> = vec![];
+
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .for_each(|line| {
+            let mut positions = line.split(": ");
+            let sensor_split = positions.next().unwrap();
+            let beacon_split = positions.next().unwrap();
+            let (sensor_x, sensor_y) = get_device_coordinates(sensor_split);
+            let (beacon_x, beacon_y) = get_device_coordinates(beacon_split);
+            let mut sensor = Sensor {
+                x: sensor_x,
+                y: sensor_y,
+                radius: 1,
+            };
+            let beacon = Beacon {
+                x: beacon_x,
+                y: beacon_y,
+            };
+            sensor.radius = manhattan_dist(&sensor, &beacon);
+            network.push((sensor, beacon));
+        });
+
+    Tunnels {
+        network,
+        row: params.get("row"),
+        bound: params.get("bound"),
+    }
+}
+
+pub fn calculate_invalid_beacon_placements(tunnels: &Tunnels, row_num: i64) -> Option<u32> {
+    let mut invalid_beacon_placements: HashSet<(i64, i64)> = HashSet::new();
+    tunnels
+        .network
+        .iter()
+        .for_each(|(sensor, _)| {
+            if (sensor.radius - sensor.y.abs_diff(row_num) as i64) < 0 {
+                return;
+            }
+            let min_x = sensor.x - (sensor.radius - sensor.y.abs_diff(row_num) as i64);
+            let max_x = sensor.x + (sensor.radius - sensor.y.abs_diff(row_num) as i64);
+            (min_x..=max_x).for_each(|x| {
+                invalid_beacon_placements.insert((x, row_num));
+            })
+        });
+
+    let existing_beacon_count = tunnels
+        .network
+        .iter()
+        .map(|(_, beacon)| (beacon.x, beacon.y))
+        .collect::<HashSet<(i64, i64)>>()
+        .iter()
+        .filter(|(x, y)| y == &row_num && invalid_beacon_placements.contains(&(*x, *y)))
+        .count();
+
+    Some(invalid_beacon_placements.len() as u32 - existing_beacon_count as u32)
+}
+
+pub fn calculate_tuning_frequency(tunnels: &Tunnels, singal_strength: i64) -> Option<i64> {
+    let sensors = tunnels
+        .network
+        .iter()
+        .map(|(sensor, _)| sensor)
+        .collect::<Vec<&Sensor>>();
+    for sensor in sensors.iter() {
+        for x in (sensor.x - sensor.radius - 1)..=(sensor.x + sensor.radius + 1) {
+            if x > singal_strength {
+                break;
+            } else if x < 0 {
+                continue;
+            }
+
+            let delta_y = sensor.radius - (x - sensor.x).abs() + 1;
+            'a: for y in [sensor.y + delta_y, sensor.y - delta_y] {
+                if y <= singal_strength && y >= 0 {
+                    for adjacent_sensor in sensors.iter() {
+                        if (adjacent_sensor.x - x).abs() + (adjacent_sensor.y - y).abs() <= adjacent_sensor.radius {
+                            break 'a;
+                        }
+                    }
+                    return Some(x * 4_000_000 + y);
+                }
+            }
+        }
+    }
+    None
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const PARAMS: &'static [Param] = &[
+        Param::new("row", 2_000_000),
+        Param::new("bound", 4_000_000),
+    ];
+    type Input<'a> = Tunnels;
+    type PartOne = u32;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Params) -> Self::Input<'a> {
+        parse(input, params)
+    }
+
+    fn part_one(tunnels: &Self::Input<'_>) -> Option<u32> {
+        calculate_invalid_beacon_placements(tunnels, tunnels.row)
+    }
+
+    fn part_two(tunnels: &Self::Input<'_>) -> Option<i64> {
+        calculate_tuning_frequency(tunnels, tunnels.bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day15, 2022);
+}
@@ -0,0 +1,59 @@
+// A step that times out is left running and marks the whole process, see `runner::run_day`.
+// This test has its own binary so neither affects the unit tests of the library.
+use advent_of_code::answer::Answer;
+use advent_of_code::runner::{self, Outcome, RunOptions};
+use advent_of_code::solution::{Day, Params, Solution};
+use std::time::Duration;
+
+struct Endless;
+
+impl Solution for Endless {
+    const DAY: u8 = 21;
+    type Input<'a> = &'a str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(_: &Self::Input<'_>) -> Option<usize> {
+        loop {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<usize> {
+        Some(input.len())
+    }
+}
+
+#[test]
+fn test_run_day_with_timeout() {
+    let timeout = Duration::from_millis(50);
+    let options = RunOptions {
+        timeout: Some(timeout),
+        ..RunOptions::default()
+    };
+    let day = Day::new::<Endless>();
+    let params = Params::defaults(day.params);
+
+    let only_part_two = RunOptions {
+        part: Some(2),
+        ..options
+    };
+    let (_, parts) = runner::run_day(&day, "abc", &params, &only_part_two);
+    assert_eq!(parts[0].outcome, Outcome::Solved(Answer::Number(3)));
+    assert!(!runner::timed_out_in_background());
+
+    let (_, parts) = runner::run_day(&day, "abc", &params, &options);
+    let outcomes: Vec<_> = parts.into_iter().map(|part| part.outcome).collect();
+    assert_eq!(
+        outcomes,
+        [
+            Outcome::TimedOut(timeout),
+            Outcome::Skipped("part 1 timed out".to_string())
+        ]
+    );
+    assert!(runner::timed_out_in_background());
+}